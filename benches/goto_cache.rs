@@ -0,0 +1,64 @@
+//! Compares reading many descriptors of one node with and without the cached `goto` position.
+//! Run with `cargo bench --bench goto_cache`.
+
+use cgns::*;
+use std::time::{Duration, Instant};
+
+const N_DESCRIPTORS: i32 = 10_000;
+
+fn read_descriptors(base: &Base<ModifiableFile>, cached: bool) -> Duration {
+    let start = Instant::now();
+
+    for descriptor in Descriptor::iter(base).expect("failed to iterate descriptors") {
+        if !cached {
+            base.lib().invalidate_goto();
+        }
+        descriptor.read().expect("failed to read descriptor");
+    }
+
+    start.elapsed()
+}
+
+fn main() {
+    let lib = Library::new();
+
+    lib.open_write("goto_bench.cgns")
+        .expect("failed to create file")
+        .close()
+        .expect("failed to close file");
+
+    let mut file = lib
+        .open_modify("goto_bench.cgns")
+        .expect("failed to open file");
+
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: CgnsName::new("Bench Base").expect("invalid name"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    let mut base = file.get_base(base_index).expect("failed to get base");
+
+    for i in 0..N_DESCRIPTORS {
+        base.set_descriptor(&DescriptorData {
+            name: CgnsName::new(&format!("Descriptor{}", i)).expect("invalid name"),
+            value: format!("Value {}", i),
+        })
+        .expect("failed to write descriptor");
+    }
+
+    let uncached = read_descriptors(&base, false);
+    let cached = read_descriptors(&base, true);
+
+    println!(
+        "reading {} descriptors: {:?} without goto cache, {:?} with goto cache ({:.1}x)",
+        N_DESCRIPTORS,
+        uncached,
+        cached,
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -3,7 +3,9 @@ use super::*;
 pub mod types;
 pub use types::*;
 
+use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_void};
 
 pub struct Cgio<'g, M> {
     cgio_number: i32,
@@ -29,4 +31,215 @@ impl<'g, M: OpenMode> Cgio<'g, M> {
     pub fn root_id(&self) -> f64 {
         self.root_node.id
     }
+
+    pub fn root_node(&self) -> CgioNode {
+        self.root_node
+    }
+
+    /// The child of `parent` called `name`
+    pub fn child(&self, parent: CgioNode, name: &[u8]) -> CgnsResult<CgioNode> {
+        let name = CString::new(name)?;
+        let mut id = 0.0;
+
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_get_node_id(self.cgio_number, parent.id, name.as_ptr(), &mut id)
+        })?;
+
+        Ok(CgioNode { id })
+    }
+
+    /// All children of `parent`, in the order libcgns numbers them
+    pub fn children(&self, parent: CgioNode) -> CgnsResult<Vec<CgioNode>> {
+        let mut n_children = 0;
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_number_children(self.cgio_number, parent.id, &mut n_children)
+        })?;
+
+        let mut ids = vec![0.0; n_children as usize];
+        let mut n_returned = 0;
+        if n_children > 0 {
+            to_cgio_result(unsafe {
+                cgio_bindings::cgio_children_ids(
+                    self.cgio_number,
+                    parent.id,
+                    1,
+                    n_children,
+                    &mut n_returned,
+                    ids.as_mut_ptr(),
+                )
+            })?;
+        }
+        ids.truncate(n_returned as usize);
+
+        Ok(ids.into_iter().map(|id| CgioNode { id }).collect())
+    }
+
+    /// The name of `node` as stored in the file
+    pub fn name_bytes(&self, node: CgioNode) -> CgnsResult<Vec<u8>> {
+        let mut name = [0 as c_char; 33];
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_get_name(self.cgio_number, node.id, name.as_mut_ptr())
+        })?;
+
+        Ok(unsafe { CStr::from_ptr(name.as_ptr()) }.to_bytes().to_vec())
+    }
+
+    /// The label of `node`, e.g. `CGNSBase_t`
+    pub fn label(&self, node: CgioNode) -> CgnsResult<String> {
+        let mut label = [0 as c_char; 33];
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_get_label(self.cgio_number, node.id, label.as_mut_ptr())
+        })?;
+
+        Ok(unsafe { CStr::from_ptr(label.as_ptr()) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// The type of the data of `node`, e.g. `R8`, `C1` or `MT` for nodes without data
+    pub fn data_type(&self, node: CgioNode) -> CgnsResult<String> {
+        let mut data_type = [0 as c_char; 33];
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_get_data_type(self.cgio_number, node.id, data_type.as_mut_ptr())
+        })?;
+
+        Ok(unsafe { CStr::from_ptr(data_type.as_ptr()) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// The dimensions of the data of `node`, empty for nodes without data
+    pub fn dimensions(&self, node: CgioNode) -> CgnsResult<Vec<i64>> {
+        let mut n_dims: c_int = 0;
+        let mut dims: [cgns_bindings::cgsize_t; 12] = [0; 12];
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_get_dimensions(
+                self.cgio_number,
+                node.id,
+                &mut n_dims,
+                dims.as_mut_ptr(),
+            )
+        })?;
+
+        let n_dims = (n_dims.max(0) as usize).min(dims.len());
+        // `cgsize_t` is either `i32` or `i64`, depending on how libcgns was built
+        #[allow(clippy::useless_conversion)]
+        Ok(dims[..n_dims].iter().map(|&dim| i64::from(dim)).collect())
+    }
+
+    /// The raw data of `node` in the byte order of this machine, empty for nodes without data
+    pub fn read_all_data(&self, node: CgioNode) -> CgnsResult<Vec<u8>> {
+        let data_type = self.data_type(node)?;
+        let len = data_len(&data_type, &self.dimensions(node)?)?;
+        let mut data = vec![0u8; len];
+        if len > 0 {
+            let data_type = CString::new(data_type)?;
+            to_cgio_result(unsafe {
+                cgio_bindings::cgio_read_all_data_type(
+                    self.cgio_number,
+                    node.id,
+                    data_type.as_ptr(),
+                    data.as_mut_ptr() as *mut c_void,
+                )
+            })?;
+        }
+
+        Ok(data)
+    }
+
+    /// Create a child of `parent` called `name` with `label` and `data`, which is laid out as described by
+    /// `data_type` and `dimensions` (see `read_all_data`).
+    /// Note: libcgns doesn't notice, see `File::write_tree`
+    pub fn create_node(
+        &self,
+        parent: CgioNode,
+        name: &[u8],
+        label: &str,
+        data_type: &str,
+        dimensions: &[i64],
+        data: &[u8],
+    ) -> CgnsResult<CgioNode>
+    where
+        M: OpenModeWrite,
+    {
+        if data.len() != data_len(data_type, dimensions)? {
+            return Err(CgnsError::dimension_mismatch());
+        }
+
+        let name = CString::new(name)?;
+        let label = CString::new(label)?;
+        let data_type = CString::new(data_type)?;
+        let dimensions = to_cgsize(dimensions)?;
+        let mut id = 0.0;
+
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_create_node(self.cgio_number, parent.id, name.as_ptr(), &mut id)
+        })?;
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_set_label(self.cgio_number, id, label.as_ptr())
+        })?;
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_set_dimensions(
+                self.cgio_number,
+                id,
+                data_type.as_ptr(),
+                dimensions.len() as c_int,
+                dimensions.as_ptr(),
+            )
+        })?;
+        if !data.is_empty() {
+            to_cgio_result(unsafe {
+                cgio_bindings::cgio_write_all_data(
+                    self.cgio_number,
+                    id,
+                    data.as_ptr() as *const c_void,
+                )
+            })?;
+        }
+
+        Ok(CgioNode { id })
+    }
+
+    /// Delete `node` and everything below it.
+    /// Note: libcgns doesn't notice, see `File::delete_base`
+    pub fn delete_node(&self, parent: CgioNode, node: CgioNode) -> CgnsResult<()>
+    where
+        M: OpenModeWrite,
+    {
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_delete_node(self.cgio_number, parent.id, node.id)
+        })
+    }
+
+    /// Rename `node`, which has to be a child of `parent`.
+    /// Note: libcgns doesn't notice, see `File::rename_base`
+    pub fn set_name(&self, parent: CgioNode, node: CgioNode, name: &str) -> CgnsResult<()>
+    where
+        M: OpenModeWrite,
+    {
+        let name = CString::new(name)?;
+
+        to_cgio_result(unsafe {
+            cgio_bindings::cgio_set_name(self.cgio_number, parent.id, node.id, name.as_ptr())
+        })
+    }
+}
+
+/// The number of bytes of data with `dimensions` stored as the cgio `data_type`
+fn data_len(data_type: &str, dimensions: &[i64]) -> CgnsResult<usize> {
+    use std::convert::TryFrom;
+
+    let size = match data_type {
+        "MT" => return Ok(0),
+        "C1" | "B1" => 1,
+        "I4" | "U4" | "R4" => 4,
+        "I8" | "U8" | "R8" | "X4" => 8,
+        "X8" => 16,
+        _ => return Err(CgnsError::unsupported()),
+    };
+
+    dimensions.iter().try_fold(size, |len: usize, &dim| {
+        len.checked_mul(usize::try_from(dim.max(0))?)
+            .ok_or_else(CgnsError::out_of_bounds)
+    })
 }
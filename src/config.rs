@@ -0,0 +1,107 @@
+use super::*;
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::sync::{Mutex, PoisonError};
+
+/// The signature of the error handlers libcgns accepts.
+/// `is_error` is 0 for warnings, 1 for errors and -1 for fatal errors.
+pub type RawErrorHandler = unsafe extern "C" fn(is_error: c_int, message: *mut c_char);
+
+/// Library wide options set via `cg_configure`, see `Library::configure`
+#[derive(Clone, Debug)]
+pub enum CgnsConfig {
+    /// `CG_CONFIG_ERROR`: `None` restores the default handler, which prints warnings and
+    /// fatal errors to stderr
+    ErrorHandler(Option<RawErrorHandler>),
+    /// `CG_CONFIG_COMPRESS`: rewrite modified ADF files on close to reclaim unused space
+    Compress(i32),
+    /// `CG_CONFIG_SET_PATH`: replace the search path for linked files, `None` clears it
+    SetPath(Option<String>),
+    /// `CG_CONFIG_ADD_PATH`: append to the search path for linked files
+    AddPath(String),
+    /// `CG_CONFIG_FILE_TYPE`: the backend used for newly created files
+    FileType(CgnsFileType),
+    /// `CG_CONFIG_HDF5_COMPRESS`: the deflate level (0-9) for HDF5 data, other levels fail with
+    /// `CgnsErrorKind::OutOfBounds`. libcgns reads it whenever data is written, so it applies
+    /// to all open files.
+    Hdf5Compress(i32),
+    /// `CG_CONFIG_HDF5_DISKLESS`: open HDF5 files with the in-memory core driver
+    Hdf5Diskless(bool),
+    /// `CG_CONFIG_HDF5_DISKLESS_INCR`: the number of bytes the core driver grows its memory by
+    Hdf5DisklessIncr(usize),
+    /// `CG_CONFIG_HDF5_DISKLESS_WRITE`: write in-memory files to disk when they are closed
+    Hdf5DisklessWrite(bool),
+}
+
+/// The severity of a message reported by libcgns
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CgnsMessageLevel {
+    Warning,
+    Error,
+    /// reported by `cg_error_exit`, which exits the process right after the handler returns
+    Fatal,
+}
+
+type ErrorHandler = Box<dyn Fn(CgnsMessageLevel, &str) + Send>;
+
+/// The handler `forward_error` calls. This is global, since libcgns only keeps one handler.
+static ERROR_HANDLER: Mutex<Option<ErrorHandler>> = Mutex::new(None);
+
+/// The last `CgnsConfig::FileType`, libcgns doesn't allow reading it back.
+/// `NONE` stands for the default backend of libcgns.
+pub(crate) static DEFAULT_FILE_TYPE: Mutex<CgnsFileType> = Mutex::new(CgnsFileType::NONE);
+
+unsafe extern "C" fn forward_error(is_error: c_int, message: *mut c_char) {
+    let level = match is_error {
+        0 => CgnsMessageLevel::Warning,
+        -1 => CgnsMessageLevel::Fatal,
+        _ => CgnsMessageLevel::Error,
+    };
+    let message = if message.is_null() {
+        "".into()
+    } else {
+        CStr::from_ptr(message).to_string_lossy()
+    };
+
+    // unwinding into libcgns is undefined behaviour, so panics of the handler are dropped
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let handler = ERROR_HANDLER.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(handler) = handler.as_ref() {
+            handler(level, &message);
+        }
+    }));
+}
+
+impl Library {
+    /// Report all messages of libcgns to `handler` instead of printing them: warnings, the message of
+    /// every failing call (with `CgnsMessageLevel::Error`, the call still returns a `CgnsError` as well)
+    /// and fatal errors.
+    /// NOTE: `cg_error_exit` still exits the process after reporting a fatal error to the handler.
+    pub fn set_error_handler<F>(&self, handler: F) -> CgnsResult<()>
+    where
+        F: Fn(CgnsMessageLevel, &str) + Send + 'static,
+    {
+        *ERROR_HANDLER.lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(handler));
+
+        self.configure(CgnsConfig::ErrorHandler(Some(forward_error)))
+    }
+
+    /// Restore the default libcgns error handler
+    pub fn clear_error_handler(&self) -> CgnsResult<()> {
+        self.configure(CgnsConfig::ErrorHandler(None))?;
+
+        *ERROR_HANDLER.lock().unwrap_or_else(PoisonError::into_inner) = None;
+
+        Ok(())
+    }
+
+    /// Report all messages of libcgns through the `log` crate, see `set_error_handler`
+    #[cfg(feature = "log")]
+    pub fn log_errors(&self) -> CgnsResult<()> {
+        self.set_error_handler(|level, message| match level {
+            CgnsMessageLevel::Warning => log::warn!("{}", message),
+            CgnsMessageLevel::Error | CgnsMessageLevel::Fatal => log::error!("{}", message),
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use super::*;
+
+/// The type of the values stored in a CGNS array
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataType {
+    Null,
+    UserDefined,
+    Integer,
+    LongInteger,
+    RealSingle,
+    RealDouble,
+    Character,
+    ComplexSingle,
+    ComplexDouble,
+}
+impl From<DataType> for cgns_bindings::DataType_t {
+    fn from(data_type: DataType) -> Self {
+        use DataType::*;
+        match data_type {
+            Null => cgns_bindings::DataType_t_DataTypeNull,
+            UserDefined => cgns_bindings::DataType_t_DataTypeUserDefined,
+            Integer => cgns_bindings::DataType_t_Integer,
+            LongInteger => cgns_bindings::DataType_t_LongInteger,
+            RealSingle => cgns_bindings::DataType_t_RealSingle,
+            RealDouble => cgns_bindings::DataType_t_RealDouble,
+            Character => cgns_bindings::DataType_t_Character,
+            ComplexSingle => cgns_bindings::DataType_t_ComplexSingle,
+            ComplexDouble => cgns_bindings::DataType_t_ComplexDouble,
+        }
+    }
+}
+impl std::convert::TryFrom<cgns_bindings::DataType_t> for DataType {
+    type Error = CgnsError;
+
+    fn try_from(data_type: cgns_bindings::DataType_t) -> CgnsResult<Self> {
+        use DataType::*;
+        Ok(match data_type {
+            cgns_bindings::DataType_t_DataTypeNull => Null,
+            cgns_bindings::DataType_t_DataTypeUserDefined => UserDefined,
+            cgns_bindings::DataType_t_Integer => Integer,
+            cgns_bindings::DataType_t_LongInteger => LongInteger,
+            cgns_bindings::DataType_t_RealSingle => RealSingle,
+            cgns_bindings::DataType_t_RealDouble => RealDouble,
+            cgns_bindings::DataType_t_Character => Character,
+            cgns_bindings::DataType_t_ComplexSingle => ComplexSingle,
+            cgns_bindings::DataType_t_ComplexDouble => ComplexDouble,
+            _ => Err(CgnsError::invalid_lib_result())?,
+        })
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Rust types that can be read from and written to CGNS arrays.
+/// When reading, libcgns converts the values if the array is stored with a different (numeric) `DataType`.
+pub trait CgnsScalar: Copy + private::Sealed {
+    const DATA_TYPE: DataType;
+}
+
+macro_rules! impl_cgns_scalar {
+    ($($scalar:ty => $data_type:ident),* $(,)?) => {
+        $(
+            impl private::Sealed for $scalar {}
+            impl CgnsScalar for $scalar {
+                const DATA_TYPE: DataType = DataType::$data_type;
+            }
+        )*
+    };
+}
+impl_cgns_scalar!(
+    i32 => Integer,
+    i64 => LongInteger,
+    f32 => RealSingle,
+    f64 => RealDouble,
+    u8 => Character,
+);
+
+/// The values of a CGNS array in the type they are stored as, one variant per `CgnsScalar`
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArrayData {
+    Integer(Vec<i32>),
+    LongInteger(Vec<i64>),
+    RealSingle(Vec<f32>),
+    RealDouble(Vec<f64>),
+    Character(Vec<u8>),
+}
+impl ArrayData {
+    pub fn data_type(&self) -> DataType {
+        match self {
+            ArrayData::Integer(_) => DataType::Integer,
+            ArrayData::LongInteger(_) => DataType::LongInteger,
+            ArrayData::RealSingle(_) => DataType::RealSingle,
+            ArrayData::RealDouble(_) => DataType::RealDouble,
+            ArrayData::Character(_) => DataType::Character,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            ArrayData::Integer(data) => data.len(),
+            ArrayData::LongInteger(data) => data.len(),
+            ArrayData::RealSingle(data) => data.len(),
+            ArrayData::RealDouble(data) => data.len(),
+            ArrayData::Character(data) => data.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! impl_array_data_from {
+    ($($scalar:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<Vec<$scalar>> for ArrayData {
+                fn from(data: Vec<$scalar>) -> Self {
+                    ArrayData::$variant(data)
+                }
+            }
+        )*
+    };
+}
+impl_array_data_from!(
+    i32 => Integer,
+    i64 => LongInteger,
+    f32 => RealSingle,
+    f64 => RealDouble,
+    u8 => Character,
+);
@@ -4,6 +4,16 @@ pub struct LibraryError {
     ier: i32,
     messsage: String,
 }
+impl LibraryError {
+    /// the raw error code returned by the library
+    pub fn ier(&self) -> i32 {
+        self.ier
+    }
+
+    pub fn message(&self) -> &str {
+        &self.messsage
+    }
+}
 impl std::fmt::Display for LibraryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.messsage)
@@ -16,16 +26,70 @@ impl std::fmt::Debug for LibraryError {
 }
 impl std::error::Error for LibraryError {}
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CgnsErrorKind {
+    /// `CG_ERROR` or any other failure of the cgns library
     LibraryCgns,
+    /// any failure of the cgio library without a more specific kind
     LibraryCgio,
+    /// `CG_NODE_NOT_FOUND` / `CGIO_ERR_NOT_FOUND` / `CGIO_ERR_NO_MATCH`
+    NodeNotFound,
+    /// `CG_INCORRECT_PATH`
+    IncorrectPath,
+    /// `CG_NO_INDEX_DIM`
+    NoIndexDim,
+    /// `CGIO_ERR_FILE_OPEN`
+    FileOpen,
+    /// `CGIO_ERR_FILE_MODE`
+    FileMode,
+    /// `CGIO_ERR_FILE_TYPE` / `CGIO_ERR_NOT_HDF5`
+    FileType,
+    /// `CGIO_ERR_READ_ONLY`
+    ReadOnly,
+    /// `CGIO_ERR_DIMENSIONS` / `CGIO_ERR_TOO_SMALL` / `CGIO_ERR_TOO_MANY`
+    Dimensions,
+    /// `CGIO_ERR_BAD_TYPE`
+    DataType,
     ConversionError,
     InvalidLibraryResult,
     OutOfBounds,
-    NodeNotFound,
+    InvalidPath,
+    /// the length of an array doesn't match the dimensions of the node it belongs to
+    DimensionMismatch,
+    /// the operation isn't supported (yet) for this kind of node or data
+    Unsupported,
+    /// a node name that breaks the SIDS naming rules, see `CgnsName`
+    InvalidName,
+    /// a sibling with the same name already exists
+    AlreadyExists,
     Other,
 }
+impl CgnsErrorKind {
+    fn from_cgns(ier: i32) -> Self {
+        use CgnsErrorKind::*;
+        match ier as u32 {
+            cgns_bindings::CG_NODE_NOT_FOUND => NodeNotFound,
+            cgns_bindings::CG_INCORRECT_PATH => IncorrectPath,
+            cgns_bindings::CG_NO_INDEX_DIM => NoIndexDim,
+            _ => LibraryCgns,
+        }
+    }
+
+    fn from_cgio(ier: i32) -> Self {
+        use cgio_bindings::*;
+        use CgnsErrorKind::*;
+        match ier {
+            CGIO_ERR_NOT_FOUND | CGIO_ERR_NO_MATCH => NodeNotFound,
+            CGIO_ERR_FILE_OPEN => FileOpen,
+            CGIO_ERR_FILE_MODE => FileMode,
+            CGIO_ERR_FILE_TYPE | CGIO_ERR_NOT_HDF5 => FileType,
+            CGIO_ERR_READ_ONLY => ReadOnly,
+            CGIO_ERR_DIMENSIONS | CGIO_ERR_TOO_SMALL | CGIO_ERR_TOO_MANY => Dimensions,
+            CGIO_ERR_BAD_TYPE => DataType,
+            _ => LibraryCgio,
+        }
+    }
+}
 impl std::fmt::Display for CgnsErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
@@ -33,68 +97,91 @@ impl std::fmt::Display for CgnsErrorKind {
 }
 
 pub struct CgnsError {
-    cause: Option<Box<dyn std::error::Error>>,
+    cause: Option<Box<dyn std::error::Error + Send + Sync>>,
     kind: CgnsErrorKind,
+    path: Option<CgnsPath>,
 }
 impl std::fmt::Display for CgnsError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.kind)
+        write!(f, "{}", self.kind)?;
+        if let Some(path) = &self.path {
+            write!(f, " at {}", path)?;
+        }
+        Ok(())
     }
 }
 impl std::fmt::Debug for CgnsError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CgnsError({})", self.kind)?;
+        if let Some(path) = &self.path {
+            write!(f, " at {}", path)?;
+        }
         if let Some(cause) = &self.cause {
-            write!(f, "CgnsError({}): {}", self.kind, cause)
-        } else {
-            write!(f, "CgnsError({})", self.kind)
+            write!(f, ": {}", cause)?;
         }
+        Ok(())
     }
 }
-impl std::error::Error for CgnsError {}
-impl From<std::ffi::NulError> for CgnsError {
-    fn from(err: std::ffi::NulError) -> Self {
-        CgnsError {
-            cause: Some(Box::new(err)),
-            kind: CgnsErrorKind::ConversionError,
-        }
+impl std::error::Error for CgnsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause
+            .as_ref()
+            .map(|cause| cause.as_ref() as &(dyn std::error::Error + 'static))
     }
 }
-impl From<std::str::Utf8Error> for CgnsError {
-    fn from(err: std::str::Utf8Error) -> Self {
-        CgnsError {
-            cause: Some(Box::new(err)),
-            kind: CgnsErrorKind::ConversionError,
-        }
-    }
+
+macro_rules! impl_from_conversion_error {
+    ($($error:ty),* $(,)?) => {
+        $(
+            impl From<$error> for CgnsError {
+                fn from(err: $error) -> Self {
+                    CgnsError {
+                        cause: Some(Box::new(err)),
+                        kind: CgnsErrorKind::ConversionError,
+                        path: None,
+                    }
+                }
+            }
+        )*
+    };
 }
+impl_from_conversion_error!(
+    std::ffi::NulError,
+    std::ffi::FromBytesWithNulError,
+    std::ffi::IntoStringError,
+    std::str::Utf8Error,
+    std::string::FromUtf8Error,
+    std::num::ParseIntError,
+    std::num::TryFromIntError,
+);
 
-/*
-impl From<std::ffi::FromBytesWithNulError> for CgnsError {
-    fn from(err: std::ffi::FromBytesWithNulError) -> Self {
-        CgnsError {
-            cause: Box::new(err),
-            kind: CgnsErrorKind::ConversionError,
-        }
+// `try_from` conversions between identical types, e.g. into `cgsize_t` on 64-bit builds of libcgns
+impl From<std::convert::Infallible> for CgnsError {
+    fn from(never: std::convert::Infallible) -> Self {
+        match never {}
     }
 }
-*/
+
 impl CgnsError {
     pub fn cgns(ier: i32, messsage: String) -> Self {
         Self {
-            kind: CgnsErrorKind::LibraryCgns,
+            kind: CgnsErrorKind::from_cgns(ier),
             cause: Some(Box::new(LibraryError { ier, messsage })),
+            path: None,
         }
     }
     pub fn cgio(ier: i32, messsage: String) -> Self {
         Self {
-            kind: CgnsErrorKind::LibraryCgio,
+            kind: CgnsErrorKind::from_cgio(ier),
             cause: Some(Box::new(LibraryError { ier, messsage })),
+            path: None,
         }
     }
     pub fn out_of_bounds() -> Self {
         Self {
             kind: CgnsErrorKind::OutOfBounds,
             cause: None,
+            path: None,
         }
     }
 
@@ -102,6 +189,7 @@ impl CgnsError {
         Self {
             kind: CgnsErrorKind::InvalidLibraryResult,
             cause: None,
+            path: None,
         }
     }
 
@@ -109,8 +197,103 @@ impl CgnsError {
         Self {
             kind: CgnsErrorKind::NodeNotFound,
             cause: None,
+            path: None,
+        }
+    }
+
+    /// no child of kind `label` called `name`, e.g. for a failed `find_by_name`
+    pub fn node_not_found_by_name(label: &str, name: &str) -> Self {
+        Self {
+            kind: CgnsErrorKind::NodeNotFound,
+            cause: Some(format!("no {} called {:?}", label, name).into()),
+            path: None,
+        }
+    }
+
+    /// an operation the mode the file was opened with doesn't allow, e.g. reopening a write-only file
+    pub fn file_mode() -> Self {
+        Self {
+            kind: CgnsErrorKind::FileMode,
+            cause: None,
+            path: None,
         }
     }
+
+    pub fn invalid_path() -> Self {
+        Self {
+            kind: CgnsErrorKind::InvalidPath,
+            cause: None,
+            path: None,
+        }
+    }
+
+    /// a `NodeHandle` resolved in another file than the one it was created for,
+    /// including its own file after that was closed or reopened
+    pub fn foreign_handle() -> Self {
+        Self {
+            kind: CgnsErrorKind::InvalidPath,
+            cause: Some("the handle belongs to another file or to a closed or reopened one".into()),
+            path: None,
+        }
+    }
+
+    pub fn dimension_mismatch() -> Self {
+        Self {
+            kind: CgnsErrorKind::DimensionMismatch,
+            cause: None,
+            path: None,
+        }
+    }
+
+    pub fn unsupported() -> Self {
+        Self {
+            kind: CgnsErrorKind::Unsupported,
+            cause: None,
+            path: None,
+        }
+    }
+
+    /// a node of a kind the operation can't handle, `name_path` is its name based path
+    pub fn unsupported_node(label: &str, name_path: &str) -> Self {
+        Self {
+            kind: CgnsErrorKind::Unsupported,
+            cause: Some(format!("{} node {:?}", label, name_path).into()),
+            path: None,
+        }
+    }
+
+    pub fn invalid_name(name: &str, reason: &str) -> Self {
+        Self {
+            kind: CgnsErrorKind::InvalidName,
+            cause: Some(format!("{:?}: {}", name, reason).into()),
+            path: None,
+        }
+    }
+
+    pub fn already_exists() -> Self {
+        Self {
+            kind: CgnsErrorKind::AlreadyExists,
+            cause: None,
+            path: None,
+        }
+    }
+
+    /// record the path of the node the error occurred at, unless a (more specific) path is already known
+    pub fn with_path(mut self, path: CgnsPath) -> Self {
+        if self.path.is_none() {
+            self.path = Some(path);
+        }
+        self
+    }
+
+    pub fn kind(&self) -> CgnsErrorKind {
+        self.kind
+    }
+
+    /// the path of the node the error occurred at, if known
+    pub fn path(&self) -> Option<&CgnsPath> {
+        self.path.as_ref()
+    }
 }
 
 pub type CgnsResult<T> = Result<T, CgnsError>;
@@ -119,9 +302,8 @@ pub fn to_cgns_result(ier: i32) -> CgnsResult<()> {
     use std::ffi::CStr;
     if ier != 0 {
         let error: String = unsafe { CStr::from_ptr(cgns_bindings::cg_get_error()) }
-            .to_str()
-            .unwrap()
-            .to_string();
+            .to_string_lossy()
+            .into_owned();
         Err(CgnsError::cgns(ier, error))
     } else {
         Ok(())
@@ -133,16 +315,11 @@ pub fn to_cgio_result(ier: i32) -> CgnsResult<()> {
     use std::{ffi::CStr, mem::MaybeUninit, os::raw::c_char};
     if ier != 0 {
         let mut message = [MaybeUninit::<c_char>::uninit(); CGIO_MAX_ERROR_LENGTH as usize];
-        let ier_ = unsafe { cgio_error_message(message.as_mut_ptr() as *mut c_char) };
-        assert_eq!(
-            ier, ier_,
-            "The `cgio_error_message` function should always return the
-             same error code as the one we called this function with."
-        );
+        // this returns the code of the last error, which is `ier`, so we keep the one we were called with
+        unsafe { cgio_error_message(message.as_mut_ptr() as *mut c_char) };
         let error: String = unsafe { CStr::from_ptr(message.as_ptr() as *const c_char) }
-            .to_str()
-            .unwrap()
-            .to_string();
+            .to_string_lossy()
+            .into_owned();
         Err(CgnsError::cgio(ier, error))
     } else {
         Ok(())
@@ -0,0 +1,90 @@
+use super::*;
+
+use std::marker::PhantomData;
+use std::sync::PoisonError;
+
+/// Options for creating a new file, see `Library::create`.
+/// `M` is the mode of the `File` that `open` returns, `WriteableFile` unless `modifiable` is used.
+/// Compression is a library-wide setting, see `CgnsConfig::Hdf5Compress` and `CgnsConfig::Compress`.
+pub struct FileBuilder<'l, M: OpenMode = WriteableFile> {
+    lib: &'l Library,
+    filename: String,
+    file_type: Option<CgnsFileType>,
+    _phantom: PhantomData<M>,
+}
+impl<'l> FileBuilder<'l, WriteableFile> {
+    pub(crate) fn new(lib: &'l Library, filename: &str) -> Self {
+        FileBuilder {
+            lib,
+            filename: filename.to_string(),
+            file_type: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Open the new file with `CgnsOpenMode::Modify`, so it can be read while it is written
+    /// (e.g. for `Coordinate::write`, which checks the size of the zone)
+    pub fn modifiable(self) -> FileBuilder<'l, ModifiableFile> {
+        FileBuilder {
+            lib: self.lib,
+            filename: self.filename,
+            file_type: self.file_type,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn open(self) -> CgnsResult<File<'l, WriteableFile>> {
+        self.create()
+    }
+}
+impl<'l> FileBuilder<'l, ModifiableFile> {
+    pub fn open(self) -> CgnsResult<File<'l, ModifiableFile>> {
+        // libcgns only creates files in `CgnsOpenMode::Write`
+        self.create()?.close()?;
+        File::<ModifiableFile>::open_modify(self.lib, &self.filename)
+    }
+}
+impl<'l, M: OpenMode> FileBuilder<'l, M> {
+    /// Select the backend of the new file. By default the library's default backend is used.
+    pub fn file_type(mut self, file_type: CgnsFileType) -> Self {
+        self.file_type = Some(file_type);
+        self
+    }
+
+    /// Write a file that can be read by CGNS 2.5, this selects the `ADF2` backend
+    pub fn version_2_5(self) -> Self {
+        self.file_type(CgnsFileType::ADF2)
+    }
+
+    /// Create the file with the selected backend
+    fn create(&self) -> CgnsResult<File<'l, WriteableFile>> {
+        let file_type = match self.file_type {
+            Some(file_type) => file_type,
+            None => return File::<WriteableFile>::open_write(self.lib, &self.filename),
+        };
+
+        let mut default = DEFAULT_FILE_TYPE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        to_cgns_result(unsafe { cgns_bindings::cg_set_file_type(file_type as i32) })?;
+
+        let file = File::<WriteableFile>::open_write(self.lib, &self.filename);
+
+        // only the file we just created should use this backend, restore the default
+        // set with `CgnsConfig::FileType`
+        let restored = to_cgns_result(unsafe { cgns_bindings::cg_set_file_type(*default as i32) });
+        if restored.is_err() {
+            *default = CgnsFileType::NONE;
+        }
+
+        let file = file?;
+        restored?;
+        Ok(file)
+    }
+}
+impl<'l, M: OpenMode> std::fmt::Debug for FileBuilder<'l, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FileBuilder({})", self.filename)
+    }
+}
@@ -0,0 +1,168 @@
+use super::*;
+
+/// An owned reference to a node, made up of its `CgnsPath` (which includes the file number).
+/// Unlike the borrowed node types (`Zone<'z>` borrows its `Base`, which borrows its `File`)
+/// a handle has no lifetime, so it can be stored, cloned and sent to other threads freely.
+/// It is resolved against the `File` it belongs to when it is needed, so the file stays
+/// available for everything else, e.g. `Base::write`.
+/// A handle only resolves in the `File` it was created from, and only until that is closed or
+/// reopened (e.g. by `File::delete_base`), otherwise it fails with `CgnsErrorKind::InvalidPath`.
+/// This also catches libcgns reusing the file number for another file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct NodeHandle {
+    path: CgnsPath,
+    /// the `File::generation` the handle was created in
+    generation: u64,
+}
+impl NodeHandle {
+    /// Create a handle for `node`
+    pub fn new<M: OpenMode, N: GotoTarget<M> + BaseRefNode<M>>(node: &N) -> Self {
+        Self::from_path(node.file(), node.path())
+    }
+
+    /// Create a handle for the node at `path` in `file`
+    pub fn from_path<M: OpenMode>(file: &File<M>, path: CgnsPath) -> Self {
+        NodeHandle {
+            path,
+            generation: file.generation(),
+        }
+    }
+
+    pub fn path(&self) -> &CgnsPath {
+        &self.path
+    }
+
+    pub fn file_number(&self) -> i32 {
+        self.path.file_number
+    }
+
+    fn check_file<M: OpenMode>(&self, file: &File<M>) -> CgnsResult<()> {
+        if self.path.file_number != file.file_number() || self.generation != file.generation() {
+            return Err(CgnsError::foreign_handle().with_path(self.path.clone()));
+        }
+        Ok(())
+    }
+
+    fn index_at(&self, position: usize, label: CgnsNodeLabel) -> CgnsResult<i32> {
+        match self.path.nodes.get(position) {
+            Some((node_label, index)) if *node_label == label => Ok(*index),
+            _ => Err(CgnsError::node_not_found().with_path(self.path.clone())),
+        }
+    }
+
+    pub fn goto<M: OpenMode>(&self, file: &File<M>) -> CgnsResult<()> {
+        self.check_file(file)?;
+        file.lib.goto(&self.path)
+    }
+
+    /// Resolve the base this node belongs to (the node itself for handles to bases)
+    pub fn base<'b, M>(&self, file: &'b File<'b, M>) -> CgnsResult<Base<'b, M>>
+    where
+        M: OpenModeRead,
+    {
+        self.check_file(file)?;
+        Base::new(file, self.path.base_index).map_err(|err| err.with_path(self.path.clone()))
+    }
+
+    /// Resolve the zone this node belongs to (the node itself for handles to zones) and pass it to `f`
+    pub fn with_zone<M, R>(
+        &self,
+        file: &File<M>,
+        f: impl FnOnce(&mut Zone<M>) -> R,
+    ) -> CgnsResult<R>
+    where
+        M: OpenModeRead,
+    {
+        let zone_index = self.index_at(0, CgnsNodeLabel::Zone)?;
+
+        let base = self.base(file)?;
+        let mut zone =
+            Zone::new(&base, zone_index).map_err(|err| err.with_path(self.path.clone()))?;
+
+        Ok(f(&mut zone))
+    }
+
+    /// Resolve the flow solution this node belongs to (the node itself for handles to solutions)
+    /// and pass it to `f`
+    pub fn with_flow_solution<M, R>(
+        &self,
+        file: &File<M>,
+        f: impl FnOnce(&mut FlowSolution<M>) -> R,
+    ) -> CgnsResult<R>
+    where
+        M: OpenModeRead,
+    {
+        let solution_index = self.index_at(1, CgnsNodeLabel::FlowSolution)?;
+
+        self.with_zone(file, |zone| {
+            let mut solution = FlowSolution::new(zone, solution_index)
+                .map_err(|err| err.with_path(self.path.clone()))?;
+            Ok(f(&mut solution))
+        })?
+    }
+
+    /// Resolve the node itself and pass it to `f`, e.g. to read or write its descriptors
+    pub fn with_node<M, R>(
+        &self,
+        file: &File<M>,
+        f: impl FnOnce(&mut ResolvedNode<M>) -> R,
+    ) -> CgnsResult<R>
+    where
+        M: OpenModeRead,
+    {
+        let mut node = ResolvedNode {
+            base: self.base(file)?,
+            path: self.path.clone(),
+        };
+        Ok(f(&mut node))
+    }
+
+    /// Resolve the descriptor this handle points to and pass it to `f`
+    pub fn with_descriptor<M, R>(
+        &self,
+        file: &File<M>,
+        f: impl FnOnce(Descriptor<M, ResolvedNode<M>>) -> R,
+    ) -> CgnsResult<R>
+    where
+        M: OpenModeRead,
+    {
+        let descriptor_index = match self.path.nodes.last() {
+            Some((CgnsNodeLabel::Descriptor, index)) => *index,
+            _ => return Err(CgnsError::node_not_found().with_path(self.path.clone())),
+        };
+
+        let mut parent_path = self.path.clone();
+        parent_path.nodes.pop();
+        let parent = ResolvedNode {
+            base: self.base(file)?,
+            path: parent_path,
+        };
+        let descriptor = Descriptor::new(&parent, descriptor_index)
+            .map_err(|err| err.with_path(self.path.clone()))?;
+
+        Ok(f(descriptor))
+    }
+}
+impl std::fmt::Debug for NodeHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NodeHandle({})", self.path)
+    }
+}
+
+/// Any node resolved from a `NodeHandle`, addressed by its path below a checked `Base`.
+/// It can be the parent of descriptors like the typed nodes.
+pub struct ResolvedNode<'b, M: OpenMode> {
+    base: Base<'b, M>,
+    path: CgnsPath,
+}
+impl<'b, M: OpenMode> Node for ResolvedNode<'b, M> {}
+impl<'b, M: OpenMode> GotoTarget<M> for ResolvedNode<'b, M> {
+    fn path(&self) -> CgnsPath {
+        self.path.clone()
+    }
+}
+impl<'b, M: OpenMode> BaseRefNode<M> for ResolvedNode<'b, M> {
+    fn base(&self) -> &Base<'_, M> {
+        &self.base
+    }
+}
@@ -3,41 +3,72 @@ pub use libcgns_sys::{cgio as cgio_bindings, cgns as cgns_bindings};
 #[macro_use]
 pub mod errors;
 pub mod cgio;
+pub mod config;
+pub mod data_type;
+pub mod file_builder;
+pub mod handle;
+pub mod name;
 pub mod node_traits;
 pub mod nodes;
 pub mod open_modes;
+pub mod tree;
 pub mod types;
 
 pub use cgio::*;
+pub use config::*;
+pub use data_type::*;
 pub use errors::*;
+pub use file_builder::*;
+pub use handle::*;
+pub use name::*;
 pub use node_traits::*;
 pub use nodes::*;
 pub use open_modes::*;
+pub use tree::*;
 pub use types::*;
 
+use std::cell::RefCell;
 use std::ffi::CString;
 use std::marker::PhantomData;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError, TryLockError};
 
-/// Assures at runtime that the Library struct is not instanciated twice
-/// You _really_ shouldn't touch this, since the wrapper relies on `Library` being a singleton
-pub static LIB_IN_USE: AtomicBool = AtomicBool::new(false);
+/// Guards all access to the CGNS library, which keeps global state (open files, the `goto` position, ...).
+/// Every `Library` holds this lock for as long as it exists.
+static LIB_LOCK: Mutex<()> = Mutex::new(());
 
 /// represents access to the CGNS library. Only one instance can exist at a time due to
-/// the design of the CGNS library
+/// the design of the CGNS library, other threads wait in `take` until it is dropped
 pub struct Library {
+    /// the last position we `goto`ed, `None` if it is unknown
+    goto_position: RefCell<Option<CgnsPath>>,
+    _guard: MutexGuard<'static, ()>,
     _phantom: PhantomData<*const ()>,
 }
 impl Library {
     pub fn new() -> Self {
         Self::take()
     }
+
+    /// Acquire the library, blocking until no other `Library` exists.
+    /// Note: calling this while the current thread already holds a `Library` deadlocks.
     pub fn take() -> Self {
-        if LIB_IN_USE.compare_and_swap(false, true, Ordering::Acquire) {
-            panic!("The CGNS library is already in use.");
+        // a panic while holding the lock leaves no state behind that the lock protects
+        Self::from_guard(LIB_LOCK.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Acquire the library if no other `Library` currently exists
+    pub fn try_take() -> Option<Self> {
+        match LIB_LOCK.try_lock() {
+            Ok(guard) => Some(Self::from_guard(guard)),
+            Err(TryLockError::Poisoned(err)) => Some(Self::from_guard(err.into_inner())),
+            Err(TryLockError::WouldBlock) => None,
         }
+    }
 
+    fn from_guard(guard: MutexGuard<'static, ()>) -> Self {
         Self {
+            goto_position: RefCell::new(None),
+            _guard: guard,
             _phantom: Default::default(),
         }
     }
@@ -62,7 +93,105 @@ impl Library {
         File::<ModifiableFile>::open_modify(self, filename)
     }
 
+    /// Check whether `filename` is a CGNS file without opening it.
+    /// Returns the backend of the file or `None` if it isn't a (readable) CGNS file.
+    pub fn is_cgns(&self, filename: &str) -> CgnsResult<Option<CgnsFileType>> {
+        use std::convert::TryFrom;
+
+        let filename = CString::new(filename)?;
+        let mut file_type = 0;
+
+        let ier = unsafe { cgns_bindings::cg_is_cgns(filename.as_ptr(), &mut file_type) };
+
+        if ier == cgns_bindings::CG_OK as i32 {
+            Ok(Some(CgnsFileType::try_from(file_type)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Change a library wide option
+    pub fn configure(&self, option: CgnsConfig) -> CgnsResult<()> {
+        use cgns_bindings::*;
+        use std::os::raw::c_void;
+
+        // the library only copies the strings we pass, so they only need to live for the call
+        let path;
+        let file_type = match option {
+            CgnsConfig::FileType(file_type) => Some(file_type),
+            _ => None,
+        };
+
+        let (what, value) = match option {
+            CgnsConfig::ErrorHandler(handler) => (
+                CG_CONFIG_ERROR,
+                handler.map_or(std::ptr::null_mut(), |handler| handler as *mut c_void),
+            ),
+            CgnsConfig::Compress(level) => (CG_CONFIG_COMPRESS, level as isize as *mut c_void),
+            CgnsConfig::SetPath(None) => (CG_CONFIG_SET_PATH, std::ptr::null_mut()),
+            CgnsConfig::SetPath(Some(new_path)) => {
+                path = CString::new(new_path)?;
+                (CG_CONFIG_SET_PATH, path.as_ptr() as *mut c_void)
+            }
+            CgnsConfig::AddPath(new_path) => {
+                path = CString::new(new_path)?;
+                (CG_CONFIG_ADD_PATH, path.as_ptr() as *mut c_void)
+            }
+            CgnsConfig::FileType(file_type) => {
+                (CG_CONFIG_FILE_TYPE, file_type as usize as *mut c_void)
+            }
+            CgnsConfig::Hdf5Compress(level) => {
+                if !(0..=9).contains(&level) {
+                    return Err(CgnsError::out_of_bounds());
+                }
+                (CG_CONFIG_HDF5_COMPRESS, level as isize as *mut c_void)
+            }
+            CgnsConfig::Hdf5Diskless(enable) => {
+                (CG_CONFIG_HDF5_DISKLESS, enable as usize as *mut c_void)
+            }
+            CgnsConfig::Hdf5DisklessIncr(increment) => {
+                (CG_CONFIG_HDF5_DISKLESS_INCR, increment as *mut c_void)
+            }
+            CgnsConfig::Hdf5DisklessWrite(enable) => (
+                CG_CONFIG_HDF5_DISKLESS_WRITE,
+                enable as usize as *mut c_void,
+            ),
+        };
+
+        to_cgns_result(unsafe { cg_configure(what as i32, value) })?;
+
+        if let Some(file_type) = file_type {
+            *DEFAULT_FILE_TYPE
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = file_type;
+        }
+
+        Ok(())
+    }
+
+    /// The current `CgnsConfig::Compress` setting.
+    /// NOTE: libcgns doesn't allow reading any of the other options.
+    pub fn compress(&self) -> CgnsResult<i32> {
+        let mut compress = 0;
+
+        to_cgns_result(unsafe { cgns_bindings::cg_get_compress(&mut compress) })?;
+
+        Ok(compress)
+    }
+
+    /// Create a new file with non-default options, e.g.
+    /// `lib.create("out.cgns").file_type(CgnsFileType::HDF5).modifiable().open()`
+    pub fn create<'l>(&'l self, filename: &str) -> FileBuilder<'l> {
+        FileBuilder::new(self, filename)
+    }
+
+    /// `goto` the node at `path`. This is skipped if we are already positioned there.
     pub fn goto(&self, path: &CgnsPath) -> CgnsResult<()> {
+        if self.goto_position.borrow().as_ref() == Some(path) {
+            return Ok(());
+        }
+        self.invalidate_goto();
+
         let depth = path
             .nodes
             .len()
@@ -90,49 +219,113 @@ impl Library {
                 indicies.as_mut_ptr(),
             )
         })
+        .map_err(|err| err.with_path(path.clone()))?;
+
+        *self.goto_position.borrow_mut() = Some(path.clone());
+
+        Ok(())
     }
 
-    pub(crate) fn delete_node(&self, node_name: String) -> CgnsResult<()> {
+    /// Forget the cached `goto` position, so that the next `goto` always calls into the library.
+    /// This is done for every operation that moves the position or might free the node it points to
+    /// (writes that can overwrite nodes, deletes, closing files). It needs to be called manually after
+    /// using the raw `cgns_bindings`.
+    pub fn invalidate_goto(&self) {
+        *self.goto_position.borrow_mut() = None;
+    }
+
+    /// Check whether the node at `parent` has a child called `name`.
+    /// Note: this moves the current position.
+    pub(crate) fn child_exists(&self, parent: &CgnsPath, name: &str) -> CgnsResult<bool> {
+        let name = CString::new(name)?;
+
+        self.goto(parent)?;
+        self.invalidate_goto();
+
+        match to_cgns_result(unsafe { cgns_bindings::cg_gopath(parent.file_number, name.as_ptr()) })
+        {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == CgnsErrorKind::NodeNotFound => Ok(false),
+            Err(err) => Err(err.with_path(parent.clone())),
+        }
+    }
+
+    pub(crate) fn delete_node(&self, node_name: impl Into<Vec<u8>>) -> CgnsResult<()> {
         let node_name = CString::new(node_name)?;
+        self.invalidate_goto();
         to_cgns_result(unsafe { cgns_bindings::cg_delete_node(node_name.as_ptr()) })
     }
 
+    /// `goto` a node by its name based path, e.g. `/Base/Zone/GridCoordinates`.
+    /// Relative paths are resolved from the current position.
+    /// If a node of an absolute path doesn't exist, the error records the path of its deepest
+    /// existing ancestor below the file.
+    pub fn goto_path(&self, file_number: i32, path: &str) -> CgnsResult<()> {
+        let path: CgnsNamePath = path.parse()?;
+        let path_str = CString::new(path.to_string())?;
+
+        self.invalidate_goto();
+        to_cgns_result(unsafe { cgns_bindings::cg_gopath(file_number, path_str.as_ptr()) }).map_err(
+            |err| match self.existing_ancestor(file_number, &path) {
+                Some(ancestor) => err.with_path(ancestor),
+                None => err,
+            },
+        )
+    }
+
+    /// The deepest ancestor of `path` that exists, used to record where `goto_path` failed.
+    /// Relative paths are skipped, since a failed `cg_gopath` leaves the position undefined.
+    fn existing_ancestor(&self, file_number: i32, path: &CgnsNamePath) -> Option<CgnsPath> {
+        if !path.absolute {
+            return None;
+        }
+
+        let mut ancestor = path.parent();
+        while let Some(parent) = ancestor.filter(|parent| !parent.nodes.is_empty()) {
+            let parent_str = CString::new(parent.to_string()).ok()?;
+            let ier = unsafe { cgns_bindings::cg_gopath(file_number, parent_str.as_ptr()) };
+            if ier == cgns_bindings::CG_OK as i32 {
+                return self.current_path().ok();
+            }
+            ancestor = parent.parent();
+        }
+        None
+    }
+
     pub fn current_path(&self) -> CgnsResult<CgnsPath> {
         use std::ffi::CStr;
-        use std::mem::MaybeUninit;
         use std::os::raw::{c_char, c_int};
 
+        const MAX_DEPTH: usize = cgns_bindings::CG_MAX_GOTO_DEPTH as usize;
+
         let mut file_number = 0;
         let mut base_index = 0;
         let mut depth = 0;
 
-        let mut labels: [MaybeUninit<*mut c_char>; cgns_bindings::CG_MAX_GOTO_DEPTH as usize] =
-            [MaybeUninit::uninit(); cgns_bindings::CG_MAX_GOTO_DEPTH as usize];
-
-        let mut indecies: [MaybeUninit<c_int>; cgns_bindings::CG_MAX_GOTO_DEPTH as usize] =
-            [MaybeUninit::uninit(); cgns_bindings::CG_MAX_GOTO_DEPTH as usize];
+        // `cg_where` copies the labels into buffers provided by the caller
+        let mut label_buffs = [[0 as c_char; 33]; MAX_DEPTH];
+        let mut labels: Vec<*mut c_char> = label_buffs
+            .iter_mut()
+            .map(|buff| buff.as_mut_ptr())
+            .collect();
+        let mut indecies = [0 as c_int; MAX_DEPTH];
 
         to_cgns_result(unsafe {
             cgns_bindings::cg_where(
                 &mut file_number,
                 &mut base_index,
                 &mut depth,
-                &mut (labels.as_mut_ptr() as *mut c_char),
-                &mut (indecies.as_mut_ptr() as c_int),
+                labels.as_mut_ptr(),
+                indecies.as_mut_ptr(),
             )
         })?;
 
-        let depth = depth as usize;
+        let depth = (depth as usize).min(MAX_DEPTH);
         let mut path = Vec::with_capacity(depth);
-        for i in 0..depth {
-            unsafe {
-                let label = CStr::from_ptr(labels[i].assume_init())
-                    .to_str()?
-                    .to_string();
-                let index = indecies[i].assume_init();
-                path.push((CgnsNodeLabel::Custom(label), index));
-                // TODO: find out the actual Label instead of always using `Custom`
-            };
+        for (label, index) in labels.iter().zip(indecies.iter()).take(depth) {
+            // labels aren't names, but the file could still hold anything
+            let label = decode_lossy(unsafe { CStr::from_ptr(*label) }.to_bytes());
+            path.push((CgnsNodeLabel::from(label.as_str()), *index));
         }
 
         let path = CgnsPath {
@@ -144,13 +337,6 @@ impl Library {
         Ok(path)
     }
 }
-impl Drop for Library {
-    fn drop(&mut self) {
-        if !LIB_IN_USE.compare_and_swap(true, false, Ordering::Release) {
-            unreachable!("Singleton was instanciated twice");
-        }
-    }
-}
 impl std::fmt::Debug for Library {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         write!(f, "Library")
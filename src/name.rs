@@ -0,0 +1,128 @@
+use super::*;
+
+use std::convert::TryFrom;
+
+/// The maximum length of a node name in bytes, longer names are truncated by libcgns
+pub const CGNS_NAME_MAX_LEN: usize = 32;
+
+/// The name of a CGNS node, following the SIDS naming rules:
+/// 1 to 32 bytes long, no `/` and neither `.` nor `..`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct CgnsName(String);
+impl CgnsName {
+    pub fn new(name: &str) -> CgnsResult<Self> {
+        Self::check(name)?;
+        Ok(CgnsName(name.to_string()))
+    }
+
+    /// Names read from a file are kept as they are, even if they break the naming rules
+    pub(crate) fn new_unchecked(name: String) -> Self {
+        CgnsName(name)
+    }
+
+    /// Decode a name read from a file like `decode_lossy`. Latin-1 text can take up to twice as many
+    /// bytes as UTF-8, so if the decoded name is longer than 32 bytes every non-ASCII byte is
+    /// replaced with `?` instead. Either way the name won't round-trip to the bytes in the file.
+    pub fn from_bytes_lossy(name: &[u8]) -> Self {
+        let decoded = decode_lossy(name);
+        if decoded.len() <= CGNS_NAME_MAX_LEN {
+            return CgnsName(decoded);
+        }
+
+        CgnsName(
+            name.iter()
+                .map(|&byte| {
+                    if byte.is_ascii() {
+                        char::from(byte)
+                    } else {
+                        '?'
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Check that `name` follows the naming rules without constructing a `CgnsName`
+    pub fn check(name: &str) -> CgnsResult<()> {
+        let reason = if name.is_empty() {
+            "names can't be empty"
+        } else if name.len() > CGNS_NAME_MAX_LEN {
+            "names can't be longer than 32 bytes"
+        } else if name.contains('/') {
+            "names can't contain '/'"
+        } else if name == "." || name == ".." {
+            "names can't be '.' or '..'"
+        } else {
+            return Ok(());
+        };
+
+        Err(CgnsError::invalid_name(name, reason))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+impl std::ops::Deref for CgnsName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+impl AsRef<str> for CgnsName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CgnsName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl std::str::FromStr for CgnsName {
+    type Err = CgnsError;
+
+    fn from_str(name: &str) -> CgnsResult<Self> {
+        Self::new(name)
+    }
+}
+impl TryFrom<&str> for CgnsName {
+    type Error = CgnsError;
+
+    fn try_from(name: &str) -> CgnsResult<Self> {
+        Self::new(name)
+    }
+}
+impl TryFrom<String> for CgnsName {
+    type Error = CgnsError;
+
+    fn try_from(name: String) -> CgnsResult<Self> {
+        Self::check(&name)?;
+        Ok(CgnsName(name))
+    }
+}
+impl From<CgnsName> for String {
+    fn from(name: CgnsName) -> Self {
+        name.0
+    }
+}
+impl PartialEq<str> for CgnsName {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl PartialEq<&str> for CgnsName {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
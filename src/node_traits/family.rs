@@ -9,28 +9,46 @@ where
     fn parent(&self) -> &Self::Parent;
 }
 
+/// A child that exists at most once below its parent
 pub trait OnlyChildNode<'p, M: OpenMode + 'p>: ChildNode<'p, M> {
     fn new(parent: &'p Self::Parent) -> Self;
+
+    /// Whether this node currently exists below its parent
+    fn exists(&self) -> CgnsResult<bool>
+    where
+        M: OpenModeRead;
 }
 
+/// Marks nodes that can have children of kind `C`
 pub trait ParentNode<'p, M: OpenMode + 'p, C>: Node
 where
     C: ChildNode<'p, M> + 'p,
 {
+    /// The number of `C` children of this node. Only-children can be queried with `OnlyChildNode::exists`.
     fn n_children(&self) -> CgnsResult<i32>
     where
         M: OpenModeRead,
-        C: SiblingNode<'p, M>;
+        C: SiblingNode<'p, M, Parent = Self>,
+    {
+        C::count(self)
+    }
 }
 
+/// A child that can exist any number of times below its parent, addressed by a (1-based) index
 pub trait SiblingNode<'p, M: OpenMode + 'p>: ChildNode<'p, M> + IndexableNode {
     fn new_unchecked(parent: &'p Self::Parent, index: i32) -> Self;
+
+    /// The number of nodes of this kind below `parent`
+    fn count(parent: &Self::Parent) -> CgnsResult<i32>
+    where
+        M: OpenModeRead;
+
     fn new(parent: &'p Self::Parent, index: i32) -> CgnsResult<Self>
     where
         Self: Sized,
         M: OpenModeRead,
     {
-        if index > 0 && index <= parent.n_children()? {
+        if index > 0 && index <= Self::count(parent)? {
             Ok(Self::new_unchecked(parent, index))
         } else {
             Err(CgnsError::out_of_bounds())
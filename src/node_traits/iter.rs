@@ -31,10 +31,33 @@ where
     fn iter(parent: &'p Self::Parent) -> CgnsResult<NodeIter<'p, M, Self>> {
         Ok(NodeIter {
             current: 0,
-            n_items: parent.n_children()?,
+            n_items: Self::count(parent)?,
             parent,
         })
     }
+
+    /// Find the child of `parent` called `name`.
+    /// If there is none, the error records the path of `parent` (base index 0 for the file itself).
+    fn find_by_name(parent: &'p Self::Parent, name: &str) -> CgnsResult<Self>
+    where
+        Self: NamedNode<M> + GotoTarget<M>,
+    {
+        // compare the raw names, so siblings that aren't valid UTF-8 can't fail the search
+        for node in Self::iter(parent)? {
+            if node.name_bytes()? == name.as_bytes() {
+                return Ok(node);
+            }
+        }
+
+        let mut parent_path = Self::new_unchecked(parent, 0).path();
+        if parent_path.nodes.pop().is_none() {
+            parent_path.base_index = 0;
+        }
+        Err(
+            CgnsError::node_not_found_by_name(&Self::NODE_LABEL.to_string(), name)
+                .with_path(parent_path),
+        )
+    }
 }
 impl<'p, M: OpenMode + 'p, N> IterableNode<'p, M> for N
 where
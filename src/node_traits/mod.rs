@@ -28,10 +28,33 @@ pub trait RwNode<'n, M: OpenMode + 'n>: ChildNode<'n, M> {
     where
         M: OpenModeWrite;
 
+    /// Write `data` over the existing sibling with the same name, used by `update`.
+    /// libcgns already does this in `write` for most nodes, so this defaults to `write`.
+    fn overwrite(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32>
+    where
+        M: OpenModeWrite,
+    {
+        Self::write(parent, data)
+    }
+
+    /// Overwrite the existing sibling with the same name as `data`. Unlike `delete` + `write`
+    /// this keeps the index of the node (and of all of its siblings) unchanged.
+    /// Note: CGNS replaces the node itself, so any children of an overwritten base, zone or flow
+    /// solution are lost.
+    fn update(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32>
+    where
+        M: OpenModeWrite + OpenModeRead,
+        Self: UpdatableNode<'n, M>,
+    {
+        let index = Self::index_by_name(parent, Self::item_name(data))?;
+        Self::overwrite(parent, data)?;
+        Ok(index)
+    }
+
     /// Note: this invalidates sibling nodes with a higher index
     // TODO: should we check that there are no such nodes?
     // TODO: relax trait bounds?
-    fn delete_by_name(self, parent: &mut Self::Parent, name: String) -> CgnsResult<()>
+    fn delete_by_name(self, parent: &mut Self::Parent, name: impl Into<Vec<u8>>) -> CgnsResult<()>
     where
         M: OpenModeWrite + OpenModeRead,
         Self: Sized + GotoTarget<M>,
@@ -48,7 +71,15 @@ pub trait RwNode<'n, M: OpenMode + 'n>: ChildNode<'n, M> {
         Self: Sized + GotoTarget<M> + NamedNode<M>,
         Self::Parent: GotoTarget<M> + BaseRefNode<M>,
     {
-        let name = self.name()?;
+        let name = self.name_bytes()?;
         self.delete_by_name(parent, name)
     }
 }
+
+/// Nodes that CGNS overwrites in place when a sibling with the same name is written
+pub trait UpdatableNode<'n, M: OpenMode + 'n>: RwNode<'n, M> {
+    fn item_name(data: &Self::Item) -> &str;
+    fn index_by_name(parent: &Self::Parent, name: &str) -> CgnsResult<i32>
+    where
+        M: OpenModeRead;
+}
@@ -5,8 +5,13 @@ pub trait LabeledNode {
 }
 
 pub trait NamedNode<M: OpenMode>: LabeledNode {
+    /// The name as stored in the file, which needn't be valid UTF-8.
+    /// Implementations read as little as libcgns allows, since `find_by_name` calls this for every sibling.
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        Ok(Self::NODE_LABEL.to_string().into_bytes())
+    }
     fn name(&self) -> CgnsResult<String> {
-        Ok(Self::NODE_LABEL.to_string())
+        Ok(String::from_utf8(self.name_bytes()?)?)
     }
 }
 
@@ -12,9 +12,15 @@ impl<'b, M: OpenMode> Base<'b, M> {
     pub fn n_zones(&self) -> CgnsResult<i32>
     where
         M: OpenModeRead,
-        Self: ParentNode<'b, M, Zone<'b, M>>,
     {
-        self.n_children()
+        Zone::count(self)
+    }
+
+    pub fn has_simulation_type(&'b self) -> CgnsResult<bool>
+    where
+        M: OpenModeRead,
+    {
+        SimulationType::new(self).exists()
     }
 
     pub fn get_zone<'z>(&'z self, zone_index: i32) -> CgnsResult<Zone<'z, M>>
@@ -24,6 +30,13 @@ impl<'b, M: OpenMode> Base<'b, M> {
         Zone::new(self, zone_index)
     }
 
+    pub fn zone_by_name<'z>(&'z self, name: &str) -> CgnsResult<Zone<'z, M>>
+    where
+        M: OpenModeRead,
+    {
+        Zone::find_by_name(self, name)
+    }
+
     pub fn zones<'z>(&'z self) -> CgnsResult<NodeIter<'z, M, Zone<'z, M>>>
     where
         M: OpenModeRead,
@@ -36,13 +49,54 @@ impl<'b, M: OpenMode> Base<'b, M> {
         self.base_index
     }
 
-    /// Get the cell dimension for the CGNS base
+    /// Like `read`, but decodes a name that isn't valid UTF-8 as Latin-1 instead of failing
+    /// (see `CgnsName::from_bytes_lossy`)
+    pub fn read_lossy(&self) -> CgnsResult<BaseData>
+    where
+        M: OpenModeRead,
+    {
+        let (name, cell_dim, phys_dim) = self.read_raw()?;
+
+        Ok(BaseData {
+            name: CgnsName::from_bytes_lossy(&name),
+            cell_dim,
+            phys_dim,
+        })
+    }
+
+    /// Read the name bytes, cell and physical dimension
+    pub(crate) fn read_raw(&self) -> CgnsResult<(Vec<u8>, i32, i32)> {
+        let mut cell_dim = 0;
+        let mut phys_dim = 0;
+        let mut basename: [MaybeUninit<c_char>; 33] = [MaybeUninit::uninit(); 33];
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_base_read(
+                self.file().file_number(),
+                self.index(),
+                basename.as_mut_ptr() as *mut c_char,
+                &mut cell_dim,
+                &mut phys_dim,
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        let name = unsafe { CStr::from_ptr(basename.as_ptr() as *const c_char) }
+            .to_bytes()
+            .to_vec();
+
+        Ok((name, cell_dim, phys_dim))
+    }
+
+    /// Get the cell dimension for the CGNS base.
+    /// Note: this is a read, which fails for files opened with `CgnsOpenMode::Write`
     pub fn dim(&self) -> CgnsResult<i32> {
         let mut cell_dim = 0;
 
         to_cgns_result(unsafe {
             cgns_bindings::cg_cell_dim(self.file().file_number(), self.index(), &mut cell_dim)
-        })?;
+        })
+        .map_err(|err| err.with_path(self.path()))?;
 
         Ok(cell_dim)
     }
@@ -52,8 +106,8 @@ impl<'b, M: OpenMode> LabeledNode for Base<'b, M> {
     const NODE_LABEL: CgnsNodeLabel = CgnsNodeLabel::Base;
 }
 impl<'b, M: OpenModeRead> NamedNode<M> for Base<'b, M> {
-    fn name(&self) -> CgnsResult<String> {
-        Ok(String::from(&self.read()?.name))
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        Ok(self.read_raw()?.0)
     }
 }
 impl<'b, M: OpenMode> GotoTarget<M> for Base<'b, M> {
@@ -84,48 +138,64 @@ impl<'b, M: OpenMode> RwNode<'b, M> for Base<'b, M> {
 
     /// Read CGNS base information
     fn read(&self) -> CgnsResult<BaseData> {
-        let mut cell_dim = 0;
-        let mut phys_dim = 0;
-        let mut basename: [MaybeUninit<c_char>; 33] = [MaybeUninit::uninit(); 33];
-
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_base_read(
-                self.file().file_number(),
-                self.index(),
-                basename.as_mut_ptr() as *mut c_char,
-                &mut cell_dim,
-                &mut phys_dim,
-            )
-        })?;
-
-        let name: String = unsafe { CStr::from_ptr(basename.as_ptr() as *const i8) }
-            .to_str()?
-            .to_string();
+        let (name, cell_dim, phys_dim) = self.read_raw()?;
 
         Ok(BaseData {
-            name,
+            name: CgnsName::new_unchecked(String::from_utf8(name)?),
             cell_dim,
             phys_dim,
         })
     }
 
-    /// Create and/or write to a CGNS base node
+    /// Create a new CGNS base node.
+    /// Fails with `CgnsErrorKind::AlreadyExists` if there already is a base with the same name, use `update` to replace it.
     fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
-        // file_number: c_int,
-        let basename = CString::new(data.name.clone())?;
-        let mut base_index = 0;
+        if let Some(base_index) = parent.base_index_by_name(&data.name)? {
+            return Err(CgnsError::already_exists().with_path(parent.base_path(base_index)));
+        }
 
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_base_write(
-                parent.file_number(),
-                basename.as_ptr(),
-                data.cell_dim,
-                data.phys_dim,
-                &mut base_index,
-            )
-        })?;
+        write_base(parent, data)
+    }
 
-        Ok(base_index)
+    /// Replace the base with the same name as `data`, used by `update`.
+    /// Note: CGNS replaces the node itself, so all zones and other children of the base are lost.
+    fn overwrite(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32>
+    where
+        M: OpenModeWrite,
+    {
+        write_base(parent, data)
+    }
+}
+
+/// Write a base, libcgns overwrites an existing base with the same name (unless the file was opened with `CgnsOpenMode::Write`)
+fn write_base<M: OpenMode>(file: &File<M>, data: &BaseData) -> CgnsResult<i32> {
+    let basename = CString::new(data.name.as_str())?;
+    let mut base_index = 0;
+
+    file.lib.invalidate_goto();
+
+    to_cgns_result(unsafe {
+        cgns_bindings::cg_base_write(
+            file.file_number(),
+            basename.as_ptr(),
+            data.cell_dim,
+            data.phys_dim,
+            &mut base_index,
+        )
+    })?;
+
+    Ok(base_index)
+}
+impl<'b, M: OpenMode> UpdatableNode<'b, M> for Base<'b, M> {
+    fn item_name(data: &Self::Item) -> &str {
+        &data.name
+    }
+
+    fn index_by_name(parent: &Self::Parent, name: &str) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        Ok(parent.base_by_name(name)?.index())
     }
 }
 impl<'b, M: OpenMode> ChildNode<'b, M> for Base<'b, M> {
@@ -147,32 +217,21 @@ impl<'b, M: OpenMode> SiblingNode<'b, M> for Base<'b, M> {
             base_index,
         }
     }
+
+    fn count(parent: &Self::Parent) -> CgnsResult<i32> {
+        let mut nbases = 0;
+        to_cgns_result(unsafe { cgns_bindings::cg_nbases(parent.file_number(), &mut nbases) })?;
+        Ok(nbases)
+    }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaseData {
-    pub name: String,
+    pub name: CgnsName,
     pub cell_dim: i32,
     pub phys_dim: i32,
 }
 
-impl<'b, M: OpenMode> ParentNode<'b, M, SimulationType<'b, M>> for Base<'b, M> {
-    fn n_children(&self) -> CgnsResult<i32> {
-        unreachable!()
-    }
-}
-
-impl<'b, M: OpenMode> ParentNode<'b, M, Zone<'b, M>> for Base<'b, M> {
-    fn n_children(&self) -> CgnsResult<i32>
-    where
-        M: OpenModeRead,
-    {
-        let mut nzones = 0;
-
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_nzones(self.file().file_number(), self.index(), &mut nzones)
-        })?;
-
-        Ok(nzones)
-    }
-}
+impl<'b, M: OpenMode> ParentNode<'b, M, SimulationType<'b, M>> for Base<'b, M> {}
+impl<'b, M: OpenMode> ParentNode<'b, M, Zone<'b, M>> for Base<'b, M> {}
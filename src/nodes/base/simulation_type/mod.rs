@@ -1,5 +1,7 @@
 use super::*;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimulationTypeData {
     Null,
     UserDefined,
@@ -38,7 +40,8 @@ impl<'s, M: OpenMode> RwNode<'s, M> for SimulationType<'s, M> {
                 self.base().index(),
                 &mut simulation_type,
             )
-        })?;
+        })
+        .map_err(|err| err.with_path(self.path()))?;
 
         Ok(match simulation_type {
             cgns_bindings::CG_Null => SimulationTypeData::Null,
@@ -57,13 +60,15 @@ impl<'s, M: OpenMode> RwNode<'s, M> for SimulationType<'s, M> {
             SimulationTypeData::NonTimeAccurate => cgns_bindings::SimulationType_t_NonTimeAccurate,
         };
 
+        parent.lib().invalidate_goto();
         to_cgns_result(unsafe {
             cgns_bindings::cg_simulation_type_write(
                 parent.file().file_number(),
                 parent.index(),
                 simulation_type,
             )
-        })?;
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
 
         Ok(0)
     }
@@ -79,4 +84,8 @@ impl<'s, M: OpenMode> OnlyChildNode<'s, M> for SimulationType<'s, M> {
     fn new(parent: &'s Self::Parent) -> Self {
         SimulationType { base: parent }
     }
+
+    fn exists(&self) -> CgnsResult<bool> {
+        self.lib().child_exists(&self.base.path(), "SimulationType")
+    }
 }
@@ -0,0 +1,221 @@
+use super::*;
+use std::{
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+    os::raw::c_char,
+};
+
+pub struct FlowSolution<'s, M: OpenMode> {
+    zone: &'s Zone<'s, M>,
+    solution_index: i32,
+}
+
+/// A single solution field (e.g. `Density`) of a `FlowSolution`
+pub type Field<'f, M> = DataArray<'f, M, FlowSolution<'f, M>>;
+/// One value per vertex or cell, depending on the location of the solution, see `DataArrayData`
+pub type FieldData = DataArrayData;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlowSolutionData {
+    pub name: CgnsName,
+    /// where the fields of this solution are located, only `Vertex` and `CellCenter` are supported for fields
+    pub location: GridLocation,
+}
+impl<'s, M: OpenMode> FlowSolution<'s, M> {
+    pub fn n_fields(&self) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        Field::count(self)
+    }
+
+    pub fn get_field<'f>(&'f self, field_index: i32) -> CgnsResult<Field<'f, M>>
+    where
+        M: OpenModeRead,
+    {
+        Field::new(self, field_index)
+    }
+
+    pub fn field_by_name<'f>(&'f self, name: &str) -> CgnsResult<Field<'f, M>>
+    where
+        M: OpenModeRead,
+    {
+        Field::find_by_name(self, name)
+    }
+
+    pub fn fields<'f>(&'f self) -> CgnsResult<NodeIter<'f, M, Field<'f, M>>>
+    where
+        M: OpenModeRead,
+    {
+        Field::iter(self)
+    }
+
+    /// Like `read`, but decodes a name that isn't valid UTF-8 as Latin-1 instead of failing
+    /// (see `CgnsName::from_bytes_lossy`)
+    pub fn read_lossy(&self) -> CgnsResult<FlowSolutionData>
+    where
+        M: OpenModeRead,
+    {
+        let (name, location) = self.read_raw_unchecked()?;
+
+        Ok(FlowSolutionData {
+            name: CgnsName::from_bytes_lossy(&name),
+            location,
+        })
+    }
+
+    /// Read the solution without requiring `OpenModeRead`, see `Zone::read_unchecked`
+    pub(crate) fn read_unchecked(&self) -> CgnsResult<FlowSolutionData> {
+        let (name, location) = self.read_raw_unchecked()?;
+
+        Ok(FlowSolutionData {
+            name: CgnsName::new_unchecked(String::from_utf8(name)?),
+            location,
+        })
+    }
+
+    /// Read the name bytes and location
+    fn read_raw_unchecked(&self) -> CgnsResult<(Vec<u8>, GridLocation)> {
+        use std::convert::TryFrom;
+
+        let (name, location) = self.read_info_unchecked()?;
+        Ok((name, GridLocation::try_from(location)?))
+    }
+
+    /// The raw `cg_sol_info` result, without converting the location
+    fn read_info_unchecked(&self) -> CgnsResult<(Vec<u8>, cgns_bindings::GridLocation_t)> {
+        let mut name = [MaybeUninit::<c_char>::uninit(); 33];
+        let mut location = 0;
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_sol_info(
+                self.file().file_number(),
+                self.base().index(),
+                self.zone.index(),
+                self.solution_index,
+                name.as_mut_ptr() as *mut c_char,
+                &mut location,
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        Ok((
+            unsafe { CStr::from_ptr(name.as_ptr() as *const c_char) }
+                .to_bytes()
+                .to_vec(),
+            location,
+        ))
+    }
+}
+impl<'s, M: OpenMode> Node for FlowSolution<'s, M> {}
+impl<'s, M: OpenMode> LabeledNode for FlowSolution<'s, M> {
+    const NODE_LABEL: CgnsNodeLabel = CgnsNodeLabel::FlowSolution;
+}
+impl<'s, M: OpenMode> NamedNode<M> for FlowSolution<'s, M>
+where
+    M: OpenModeRead,
+{
+    /// Skips converting the location, so solutions at unknown locations can still be found by name
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        Ok(self.read_info_unchecked()?.0)
+    }
+}
+impl<'s, M: OpenMode> GotoTarget<M> for FlowSolution<'s, M> {
+    fn path(&self) -> CgnsPath {
+        let mut path = self.zone.path();
+        path.nodes
+            .push((CgnsNodeLabel::FlowSolution, self.solution_index));
+        path
+    }
+}
+impl<'s, M: OpenMode> RwNode<'s, M> for FlowSolution<'s, M> {
+    type Item = FlowSolutionData;
+    fn read(&self) -> CgnsResult<Self::Item> {
+        self.read_unchecked()
+    }
+    fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
+        let name = CString::new(data.name.as_str())?;
+        let mut solution_index = 0;
+
+        // overwriting an existing solution frees the old node
+        parent.lib().invalidate_goto();
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_sol_write(
+                parent.file().file_number(),
+                parent.base().index(),
+                parent.index(),
+                name.as_ptr(),
+                data.location.into(),
+                &mut solution_index,
+            )
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(solution_index)
+    }
+}
+impl<'s, M: OpenMode> UpdatableNode<'s, M> for FlowSolution<'s, M> {
+    fn item_name(data: &Self::Item) -> &str {
+        &data.name
+    }
+
+    fn index_by_name(parent: &Self::Parent, name: &str) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        Ok(parent.flow_solution_by_name(name)?.index())
+    }
+}
+impl<'s, M: OpenMode> ChildNode<'s, M> for FlowSolution<'s, M> {
+    type Parent = Zone<'s, M>;
+
+    fn parent(&self) -> &Self::Parent {
+        self.zone
+    }
+}
+impl<'s, M: OpenMode> BaseRefNode<M> for FlowSolution<'s, M> {
+    fn base<'b>(&'b self) -> &'b Base<M> {
+        self.zone.base()
+    }
+}
+impl<'s, M: OpenMode> IndexableNode for FlowSolution<'s, M> {
+    fn index(&self) -> i32 {
+        self.solution_index
+    }
+}
+impl<'s, M: OpenMode> SiblingNode<'s, M> for FlowSolution<'s, M> {
+    fn new_unchecked(parent: &'s Self::Parent, solution_index: i32) -> Self {
+        FlowSolution {
+            zone: parent,
+            solution_index,
+        }
+    }
+
+    fn count(parent: &Self::Parent) -> CgnsResult<i32> {
+        let mut nsols = 0;
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_nsols(
+                parent.file().file_number(),
+                parent.base().index(),
+                parent.index(),
+                &mut nsols,
+            )
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(nsols)
+    }
+}
+impl<'s, M: OpenMode> DataArrayParent<'s, M> for FlowSolution<'s, M> {
+    fn zone(&self) -> &Zone<'s, M> {
+        self.zone
+    }
+
+    fn location_unchecked(&self) -> CgnsResult<GridLocation> {
+        Ok(self.read_unchecked()?.location)
+    }
+}
+
+impl<'s, M: OpenMode> ParentNode<'s, M, Field<'s, M>> for FlowSolution<'s, M> {}
@@ -0,0 +1,5 @@
+use super::*;
+
+pub mod flow_solution;
+
+pub use flow_solution::*;
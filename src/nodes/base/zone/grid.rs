@@ -0,0 +1,196 @@
+use super::*;
+use std::{
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+    os::raw::c_char,
+};
+
+/// A `GridCoordinates_t` node of a zone, which holds its coordinate arrays.
+/// Most files have a single grid called `GridCoordinates`, moving or deforming grids can have more.
+pub struct Grid<'g, M: OpenMode> {
+    zone: &'g Zone<'g, M>,
+    grid_index: i32,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridData {
+    pub name: CgnsName,
+}
+impl Default for GridData {
+    /// The grid called `GridCoordinates`, see `Zone::grid_coordinates`
+    fn default() -> Self {
+        GridData {
+            name: CgnsName::new_unchecked(GRID_COORDINATES.to_string()),
+        }
+    }
+}
+
+/// The name of the grid libcgns stores the coordinates of a zone in
+pub(crate) const GRID_COORDINATES: &str = "GridCoordinates";
+
+/// A single coordinate array (e.g. `CoordinateX`) of a `Grid`
+pub type Coordinate<'c, M> = DataArray<'c, M, Grid<'c, M>>;
+/// One value per vertex, see `DataArrayData`
+pub type CoordinateData = DataArrayData;
+
+impl<'g, M: OpenMode> Grid<'g, M> {
+    pub fn n_coordinates(&self) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        Coordinate::count(self)
+    }
+
+    pub fn get_coordinate<'c>(&'c self, coordinate_index: i32) -> CgnsResult<Coordinate<'c, M>>
+    where
+        M: OpenModeRead,
+    {
+        Coordinate::new(self, coordinate_index)
+    }
+
+    pub fn coordinate_by_name<'c>(&'c self, name: &str) -> CgnsResult<Coordinate<'c, M>>
+    where
+        M: OpenModeRead,
+    {
+        Coordinate::find_by_name(self, name)
+    }
+
+    pub fn coordinates<'c>(&'c self) -> CgnsResult<NodeIter<'c, M, Coordinate<'c, M>>>
+    where
+        M: OpenModeRead,
+    {
+        Coordinate::iter(self)
+    }
+
+    /// Like `read`, but decodes a name that isn't valid UTF-8 as Latin-1 instead of failing
+    /// (see `CgnsName::from_bytes_lossy`)
+    pub fn read_lossy(&self) -> CgnsResult<GridData>
+    where
+        M: OpenModeRead,
+    {
+        Ok(GridData {
+            name: CgnsName::from_bytes_lossy(&self.read_name_bytes()?),
+        })
+    }
+
+    fn read_name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        let mut name = [MaybeUninit::<c_char>::uninit(); 33];
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_grid_read(
+                self.file().file_number(),
+                self.base().index(),
+                self.zone.index(),
+                self.grid_index,
+                name.as_mut_ptr() as *mut c_char,
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        Ok(unsafe { CStr::from_ptr(name.as_ptr() as *const c_char) }
+            .to_bytes()
+            .to_vec())
+    }
+}
+impl<'g, M: OpenMode> Node for Grid<'g, M> {}
+impl<'g, M: OpenMode> LabeledNode for Grid<'g, M> {
+    const NODE_LABEL: CgnsNodeLabel = CgnsNodeLabel::GridCoordinates;
+}
+impl<'g, M: OpenMode> NamedNode<M> for Grid<'g, M>
+where
+    M: OpenModeRead,
+{
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        self.read_name_bytes()
+    }
+}
+impl<'g, M: OpenMode> GotoTarget<M> for Grid<'g, M> {
+    fn path(&self) -> CgnsPath {
+        let mut path = self.zone.path();
+        path.nodes
+            .push((CgnsNodeLabel::GridCoordinates, self.grid_index));
+        path
+    }
+}
+impl<'g, M: OpenMode> RwNode<'g, M> for Grid<'g, M> {
+    type Item = GridData;
+    fn read(&self) -> CgnsResult<Self::Item> {
+        Ok(GridData {
+            name: CgnsName::new_unchecked(String::from_utf8(self.read_name_bytes()?)?),
+        })
+    }
+    /// Note: writing a grid with the name of an existing one replaces it, including all of its coordinates
+    fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
+        let name = CString::new(data.name.as_str())?;
+        let mut grid_index = 0;
+
+        // overwriting an existing grid frees the old node
+        parent.lib().invalidate_goto();
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_grid_write(
+                parent.file().file_number(),
+                parent.base().index(),
+                parent.index(),
+                name.as_ptr(),
+                &mut grid_index,
+            )
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(grid_index)
+    }
+}
+impl<'g, M: OpenMode> ChildNode<'g, M> for Grid<'g, M> {
+    type Parent = Zone<'g, M>;
+
+    fn parent(&self) -> &Self::Parent {
+        self.zone
+    }
+}
+impl<'g, M: OpenMode> BaseRefNode<M> for Grid<'g, M> {
+    fn base<'b>(&'b self) -> &'b Base<M> {
+        self.zone.base()
+    }
+}
+impl<'g, M: OpenMode> IndexableNode for Grid<'g, M> {
+    fn index(&self) -> i32 {
+        self.grid_index
+    }
+}
+impl<'g, M: OpenMode> SiblingNode<'g, M> for Grid<'g, M> {
+    fn new_unchecked(parent: &'g Self::Parent, grid_index: i32) -> Self {
+        Grid {
+            zone: parent,
+            grid_index,
+        }
+    }
+
+    fn count(parent: &Self::Parent) -> CgnsResult<i32> {
+        let mut ngrids = 0;
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_ngrids(
+                parent.file().file_number(),
+                parent.base().index(),
+                parent.index(),
+                &mut ngrids,
+            )
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(ngrids)
+    }
+}
+impl<'g, M: OpenMode> DataArrayParent<'g, M> for Grid<'g, M> {
+    fn zone(&self) -> &Zone<'g, M> {
+        self.zone
+    }
+
+    /// Coordinates always have one value per vertex
+    fn location_unchecked(&self) -> CgnsResult<GridLocation> {
+        Ok(GridLocation::Vertex)
+    }
+}
+
+impl<'g, M: OpenMode> ParentNode<'g, M, Coordinate<'g, M>> for Grid<'g, M> {}
@@ -1,5 +1,9 @@
 use super::*;
 
+pub mod flow_solution;
+pub mod grid;
 pub mod zone;
 
+pub use flow_solution::*;
+pub use grid::*;
 pub use zone::*;
@@ -9,33 +9,437 @@ pub struct Zone<'z, M: OpenMode> {
     zone_index: i32,
 }
 
-// TODO: implement constructor methods that ensure required invariants
-// i.e. n_cell = n_vertex - 1 etc.
-
+/// The size of a structured zone, with one entry per index direction (`i`, `j`, `k`).
+/// The number of directions has to match the cell dimension of the base.
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructuredZoneSize {
-    pub n_vertex: (i32, i32, i32),
-    pub n_cell: (i32, i32, i32),
+    pub n_vertex: Vec<i64>,
+    pub n_cell: Vec<i64>,
 }
+impl StructuredZoneSize {
+    /// A zone with `n_vertex` vertices in each of its 1 to 3 index directions, `n_cell` is derived
+    pub fn new(n_vertex: &[i64]) -> CgnsResult<Self> {
+        let size = StructuredZoneSize {
+            n_vertex: n_vertex.to_vec(),
+            n_cell: n_vertex.iter().map(|n_vertex| n_vertex - 1).collect(),
+        };
+        size.validate()?;
+        Ok(size)
+    }
+
+    /// The number of index directions
+    pub fn index_dim(&self) -> usize {
+        self.n_vertex.len()
+    }
+
+    /// Check that there are 1 to 3 index directions with at least one cell and `n_cell = n_vertex - 1`
+    pub fn validate(&self) -> CgnsResult<()> {
+        let valid = (1..=3).contains(&self.n_vertex.len())
+            && self.n_vertex.len() == self.n_cell.len()
+            && self
+                .n_vertex
+                .iter()
+                .zip(&self.n_cell)
+                .all(|(&n_vertex, &n_cell)| n_cell >= 1 && n_cell == n_vertex - 1);
+
+        if valid {
+            Ok(())
+        } else {
+            Err(CgnsError::dimension_mismatch())
+        }
+    }
+}
+
+/// The size of an unstructured zone, which always has a single index direction
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnstructuredZoneSize {
-    pub n_vertex: (i32, i32, i32),
-    pub n_cell: (i32, i32, i32),
-    pub b_bound_vertex: (i32, i32, i32),
+    pub n_vertex: i64,
+    pub n_cell: i64,
+    /// the number of boundary vertices, which are sorted before all other vertices. 0 if unsorted
+    pub n_boundary_vertex: i64,
+}
+impl UnstructuredZoneSize {
+    pub fn new(n_vertex: i64, n_cell: i64, n_boundary_vertex: i64) -> CgnsResult<Self> {
+        let size = UnstructuredZoneSize {
+            n_vertex,
+            n_cell,
+            n_boundary_vertex,
+        };
+        size.validate()?;
+        Ok(size)
+    }
+
+    /// Check that there is at least one vertex and cell and no more boundary vertices than vertices
+    pub fn validate(&self) -> CgnsResult<()> {
+        if self.n_vertex >= 1
+            && self.n_cell >= 1
+            && (0..=self.n_vertex).contains(&self.n_boundary_vertex)
+        {
+            Ok(())
+        } else {
+            Err(CgnsError::dimension_mismatch())
+        }
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ZoneSize {
     Structured(StructuredZoneSize),
     Unstructured(UnstructuredZoneSize),
 }
 
+impl ZoneSize {
+    /// The number of index directions, equal to the cell dimension of the base for structured zones
+    pub fn index_dim(&self) -> usize {
+        match self {
+            ZoneSize::Structured(size) => size.index_dim(),
+            ZoneSize::Unstructured(_) => 1,
+        }
+    }
+
+    pub fn validate(&self) -> CgnsResult<()> {
+        match self {
+            ZoneSize::Structured(size) => size.validate(),
+            ZoneSize::Unstructured(size) => size.validate(),
+        }
+    }
+
+    /// The number of data points in each index direction for data at `location`
+    pub fn dims(&self, location: GridLocation) -> CgnsResult<Vec<i64>> {
+        Ok(match (self, location) {
+            (ZoneSize::Structured(size), GridLocation::Vertex) => size.n_vertex.clone(),
+            (ZoneSize::Structured(size), GridLocation::CellCenter) => size.n_cell.clone(),
+            (ZoneSize::Unstructured(size), GridLocation::Vertex) => vec![size.n_vertex],
+            (ZoneSize::Unstructured(size), GridLocation::CellCenter) => vec![size.n_cell],
+            _ => Err(CgnsError::unsupported())?,
+        })
+    }
+
+    /// The total number of data points for data at `location`
+    pub fn len(&self, location: GridLocation) -> CgnsResult<usize> {
+        use std::convert::TryFrom;
+
+        self.dims(location)?.iter().try_fold(1usize, |len, &dim| {
+            len.checked_mul(usize::try_from(dim.max(0))?)
+                .ok_or_else(CgnsError::out_of_bounds)
+        })
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl ZoneSize {
+    /// The `(i, j, k)` shape of structured data at `location`, missing directions of 1-D and 2-D zones have length 1
+    pub fn shape(&self, location: GridLocation) -> CgnsResult<(usize, usize, usize)> {
+        let mut dims = self.dims(location)?;
+        dims.resize(3, 1);
+
+        match (self, dims.as_slice()) {
+            (ZoneSize::Structured(_), &[i, j, k]) => {
+                use std::convert::TryFrom;
+
+                Ok((
+                    usize::try_from(i.max(0))?,
+                    usize::try_from(j.max(0))?,
+                    usize::try_from(k.max(0))?,
+                ))
+            }
+            _ => Err(CgnsError::unsupported()),
+        }
+    }
+
+    /// Wrap data read from a structured zone, which is stored with `i` varying fastest
+    pub(crate) fn array_from_data<T>(
+        &self,
+        location: GridLocation,
+        data: Vec<T>,
+    ) -> CgnsResult<ndarray::Array3<T>> {
+        use ndarray::ShapeBuilder;
+
+        ndarray::Array3::from_shape_vec(self.shape(location)?.f(), data)
+            .map_err(|_| CgnsError::dimension_mismatch())
+    }
+
+    /// Flatten `data` into the order CGNS expects (`i` varying fastest)
+    pub(crate) fn data_from_array<T: Copy>(
+        &self,
+        location: GridLocation,
+        data: ndarray::ArrayView3<T>,
+    ) -> CgnsResult<Vec<T>> {
+        if data.dim() != self.shape(location)? {
+            return Err(CgnsError::dimension_mismatch());
+        }
+
+        // iterating the transposed view in logical order visits `i` fastest
+        Ok(data.t().iter().copied().collect())
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZoneData {
-    pub name: String,
+    pub name: CgnsName,
     pub size: ZoneSize,
 }
+impl ZoneData {
+    /// A structured zone with `n_vertex` vertices in each of its 1 to 3 index directions
+    pub fn structured(name: &str, n_vertex: &[i64]) -> CgnsResult<Self> {
+        Ok(ZoneData {
+            name: CgnsName::new(name)?,
+            size: ZoneSize::Structured(StructuredZoneSize::new(n_vertex)?),
+        })
+    }
+
+    /// An unstructured zone, `n_boundary_vertex` is 0 unless the boundary vertices are sorted first
+    pub fn unstructured(
+        name: &str,
+        n_vertex: i64,
+        n_cell: i64,
+        n_boundary_vertex: i64,
+    ) -> CgnsResult<Self> {
+        Ok(ZoneData {
+            name: CgnsName::new(name)?,
+            size: ZoneSize::Unstructured(UnstructuredZoneSize::new(
+                n_vertex,
+                n_cell,
+                n_boundary_vertex,
+            )?),
+        })
+    }
+
+    /// Check the size, `Zone::write` does this before passing it to libcgns
+    pub fn validate(&self) -> CgnsResult<()> {
+        self.size.validate()
+    }
+}
 impl<'z, M: OpenMode> Zone<'z, M> {
+    pub fn n_grids(&self) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        Grid::count(self)
+    }
+
+    pub fn get_grid<'g>(&'g self, grid_index: i32) -> CgnsResult<Grid<'g, M>>
+    where
+        M: OpenModeRead,
+    {
+        Grid::new(self, grid_index)
+    }
+
+    pub fn grid_by_name<'g>(&'g self, name: &str) -> CgnsResult<Grid<'g, M>>
+    where
+        M: OpenModeRead,
+    {
+        Grid::find_by_name(self, name)
+    }
+
+    pub fn grids<'g>(&'g self) -> CgnsResult<NodeIter<'g, M, Grid<'g, M>>>
+    where
+        M: OpenModeRead,
+    {
+        Grid::iter(self)
+    }
+
+    /// The grid called `GridCoordinates`, which most files store the coordinates of the zone in
+    pub fn grid_coordinates<'g>(&'g self) -> CgnsResult<Grid<'g, M>>
+    where
+        M: OpenModeRead,
+    {
+        self.grid_by_name(GRID_COORDINATES)
+    }
+
+    pub fn n_flow_solutions(&self) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        FlowSolution::count(self)
+    }
+
+    pub fn get_flow_solution<'s>(&'s self, solution_index: i32) -> CgnsResult<FlowSolution<'s, M>>
+    where
+        M: OpenModeRead,
+    {
+        FlowSolution::new(self, solution_index)
+    }
+
+    pub fn flow_solution_by_name<'s>(&'s self, name: &str) -> CgnsResult<FlowSolution<'s, M>>
+    where
+        M: OpenModeRead,
+    {
+        FlowSolution::find_by_name(self, name)
+    }
+
+    pub fn flow_solutions<'s>(&'s self) -> CgnsResult<NodeIter<'s, M, FlowSolution<'s, M>>>
+    where
+        M: OpenModeRead,
+    {
+        FlowSolution::iter(self)
+    }
+
+    /// Write a zone below `parent`, checking the number of index directions against `cell_dim` if it is known.
+    /// Used by `write`, and by `File::write_tree`, which knows the cell dimension without reading it.
+    pub(crate) fn write_with_cell_dim(
+        parent: &Base<'z, M>,
+        data: &ZoneData,
+        cell_dim: Option<i32>,
+    ) -> CgnsResult<i32> {
+        let mut zone_index = 0;
+
+        let name = CString::new(data.name.as_str())?;
+        data.validate()
+            .map_err(|err| err.with_path(parent.path()))?;
+        if let (ZoneSize::Structured(size), Some(cell_dim)) = (&data.size, cell_dim) {
+            if size.index_dim() as i32 != cell_dim {
+                return Err(CgnsError::dimension_mismatch().with_path(parent.path()));
+            }
+        }
+
+        let (mut size, zone_type) = match &data.size {
+            ZoneSize::Structured(size) => {
+                let mut size_buffer = Vec::with_capacity(9);
+                size_buffer.extend(&size.n_vertex);
+                size_buffer.extend(&size.n_cell);
+                // structured zones have no boundary vertices
+                size_buffer.resize(3 * size.index_dim(), 0);
+
+                (size_buffer, cgns_bindings::ZoneType_t_Structured)
+            }
+            ZoneSize::Unstructured(size) => (
+                vec![size.n_vertex, size.n_cell, size.n_boundary_vertex],
+                cgns_bindings::ZoneType_t_Unstructured,
+            ),
+        };
+        // libcgns reads as many sizes as the cell dimension of the base implies, which we don't know
+        // if the file isn't readable. With the padding it never reads past the buffer and rejects the zeros.
+        size.resize(9, 0);
+
+        let size = to_cgsize(&size)?;
+
+        parent.lib().invalidate_goto();
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_zone_write(
+                parent.file().file_number(),
+                parent.index(),
+                name.as_ptr(),
+                size.as_ptr(),
+                zone_type,
+                &mut zone_index,
+            )
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(zone_index)
+    }
+
+    /// The name as stored in the file, see `NamedNode::name_bytes`
+    fn read_name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        // NOTE: only reads the name and size, skipping the `cg_zone_type` call `read` needs
+        let mut zonename = [MaybeUninit::<c_char>::uninit(); 33];
+        let mut size_buffer: [cgns_bindings::cgsize_t; 9] = [0; 9];
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_zone_read(
+                self.file().file_number(),
+                self.base().index(),
+                self.index(),
+                zonename.as_mut_ptr() as *mut c_char,
+                size_buffer.as_mut_ptr(),
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        Ok(
+            unsafe { CStr::from_ptr(zonename.as_ptr() as *const c_char) }
+                .to_bytes()
+                .to_vec(),
+        )
+    }
+
+    /// Like `read`, but decodes a name that isn't valid UTF-8 as Latin-1 instead of failing
+    /// (see `CgnsName::from_bytes_lossy`)
+    pub fn read_lossy(&self) -> CgnsResult<ZoneData>
+    where
+        M: OpenModeRead,
+    {
+        let (name, size) = self.read_raw_unchecked()?;
+
+        Ok(ZoneData {
+            name: CgnsName::from_bytes_lossy(&name),
+            size,
+        })
+    }
+
+    /// Read the zone without requiring `OpenModeRead`, used to validate the length of arrays before writing them.
+    /// Note: this fails for files that were opened with `CgnsOpenMode::Write`, since libcgns refuses any reads.
+    pub(crate) fn read_unchecked(&self) -> CgnsResult<ZoneData> {
+        let (name, size) = self.read_raw_unchecked()?;
+
+        Ok(ZoneData {
+            name: CgnsName::new_unchecked(String::from_utf8(name)?),
+            size,
+        })
+    }
+
+    /// Read the name bytes and size
+    fn read_raw_unchecked(&self) -> CgnsResult<(Vec<u8>, ZoneSize)> {
+        let mut zone_type: cgns_bindings::ZoneType_t = 0;
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_zone_type(
+                self.file().file_number(),
+                self.base().index(),
+                self.zone_index,
+                &mut zone_type,
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        let mut zonename = [MaybeUninit::<c_char>::uninit(); 33];
+        let mut size_buffer: [cgns_bindings::cgsize_t; 9] = [0; 9];
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_zone_read(
+                self.file().file_number(),
+                self.base().index(),
+                self.index(),
+                zonename.as_mut_ptr() as *mut c_char,
+                size_buffer.as_mut_ptr(),
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        let name = unsafe { CStr::from_ptr(zonename.as_ptr() as *const c_char) }
+            .to_bytes()
+            .to_vec();
+
+        // structured: [NVertexI, (NVertexJ, NVertexK,) NCellI, (NCellJ, NCellK,) 0, (0, 0)] with `index_dim` entries each
+        // unstructured: [NVertex, NCell, NBoundVertex]
+        // `cgsize_t` is either `i32` or `i64`, depending on how libcgns was built
+        #[allow(clippy::useless_conversion)]
+        let size_buffer: Vec<i64> = size_buffer.iter().map(|&size| i64::from(size)).collect();
+        let size = match zone_type {
+            cgns_bindings::ZoneType_t_Structured => ZoneSize::Structured({
+                let index_dim = (self.index_dim()? as usize).min(3);
+                StructuredZoneSize {
+                    n_vertex: size_buffer[..index_dim].to_vec(),
+                    n_cell: size_buffer[index_dim..2 * index_dim].to_vec(),
+                }
+            }),
+            cgns_bindings::ZoneType_t_Unstructured => {
+                ZoneSize::Unstructured(UnstructuredZoneSize {
+                    n_vertex: size_buffer[0],
+                    n_cell: size_buffer[1],
+                    n_boundary_vertex: size_buffer[2],
+                })
+            }
+            _ => Err(CgnsError::invalid_lib_result())?,
+        };
+
+        Ok((name, size))
+    }
+
     pub fn index_dim(&self) -> CgnsResult<i32> {
         let mut index_dim = 0;
 
@@ -46,7 +450,8 @@ impl<'z, M: OpenMode> Zone<'z, M> {
                 self.index(),
                 &mut index_dim,
             )
-        })?;
+        })
+        .map_err(|err| err.with_path(self.path()))?;
 
         Ok(index_dim)
     }
@@ -59,8 +464,8 @@ impl<'z, M: OpenMode> NamedNode<M> for Zone<'z, M>
 where
     M: OpenModeRead,
 {
-    fn name(&self) -> CgnsResult<String> {
-        Ok(String::from(&self.read()?.name))
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        self.read_name_bytes()
     }
 }
 impl<'z, M: OpenMode> GotoTarget<M> for Zone<'z, M> {
@@ -73,121 +478,29 @@ impl<'z, M: OpenMode> GotoTarget<M> for Zone<'z, M> {
 impl<'z, M: OpenMode> RwNode<'z, M> for Zone<'z, M> {
     type Item = ZoneData;
     fn read(&self) -> CgnsResult<Self::Item> {
-        let mut zone_type: cgns_bindings::ZoneType_t = 0;
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_zone_type(
-                self.file().file_number(),
-                self.base().index(),
-                self.zone_index,
-                &mut zone_type,
-            )
-        })?;
-
-        let mut zonename = [MaybeUninit::<c_char>::uninit(); 33];
-        let mut size_buffer = [MaybeUninit::<i32>::uninit(); 9];
-
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_zone_read(
-                self.file().file_number(),
-                self.base().index(),
-                self.index(),
-                zonename.as_mut_ptr() as *mut c_char,
-                size_buffer.as_mut_ptr() as *mut i32,
-            )
-        })?;
-
-        let name: String = unsafe { CStr::from_ptr(zonename.as_ptr() as *const i8) }
-            .to_str()?
-            .to_string();
-
-        // [NVertexI, NVertexJ, NVertexK, NCellI, NCellJ, NCellK, NBoundVertexI, NBoundVertexJ, NBoundVertexK]
-        Ok(match zone_type {
-            cgns_bindings::ZoneType_t_Structured => ZoneData {
-                name,
-                size: ZoneSize::Structured(unsafe {
-                    StructuredZoneSize {
-                        n_vertex: (
-                            size_buffer[0].assume_init(),
-                            size_buffer[1].assume_init(),
-                            size_buffer[2].assume_init(),
-                        ),
-                        n_cell: (
-                            size_buffer[3].assume_init(),
-                            size_buffer[4].assume_init(),
-                            size_buffer[5].assume_init(),
-                        ),
-                    }
-                }),
-            },
-            cgns_bindings::ZoneType_t_Unstructured => ZoneData {
-                name,
-                size: ZoneSize::Unstructured(unsafe {
-                    UnstructuredZoneSize {
-                        n_vertex: (
-                            size_buffer[0].assume_init(),
-                            size_buffer[1].assume_init(),
-                            size_buffer[2].assume_init(),
-                        ),
-                        n_cell: (
-                            size_buffer[3].assume_init(),
-                            size_buffer[4].assume_init(),
-                            size_buffer[5].assume_init(),
-                        ),
-                        b_bound_vertex: (
-                            size_buffer[6].assume_init(),
-                            size_buffer[7].assume_init(),
-                            size_buffer[8].assume_init(),
-                        ),
-                    }
-                }),
-            },
-            _ => Err(CgnsError::invalid_lib_result())?,
-        })
+        self.read_unchecked()
     }
     fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
-        let mut zone_index = 0;
-
-        let name = CString::new(data.name.clone())?;
-        let (size, zone_type) = match &data.size {
-            ZoneSize::Structured(size) => {
-                let mut size_buffer = [0; 9];
-                size_buffer[0] = size.n_vertex.0;
-                size_buffer[1] = size.n_vertex.1;
-                size_buffer[2] = size.n_vertex.2;
-                size_buffer[3] = size.n_cell.0;
-                size_buffer[4] = size.n_cell.1;
-                size_buffer[5] = size.n_cell.2;
-
-                (size_buffer, cgns_bindings::ZoneType_t_Structured)
-            }
-            ZoneSize::Unstructured(size) => {
-                let mut size_buffer = [0; 9];
-                size_buffer[0] = size.n_vertex.0;
-                size_buffer[1] = size.n_vertex.1;
-                size_buffer[2] = size.n_vertex.2;
-                size_buffer[3] = size.n_cell.0;
-                size_buffer[4] = size.n_cell.1;
-                size_buffer[5] = size.n_cell.2;
-                size_buffer[6] = size.b_bound_vertex.0;
-                size_buffer[7] = size.b_bound_vertex.1;
-                size_buffer[8] = size.b_bound_vertex.2;
-
-                (size_buffer, cgns_bindings::ZoneType_t_Unstructured)
-            }
+        // `cg_cell_dim` is a read, which libcgns refuses for files opened with `CgnsOpenMode::Write`
+        let cell_dim = if parent.file().is_readable() {
+            Some(parent.dim()?)
+        } else {
+            None
         };
 
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_zone_write(
-                parent.file().file_number(),
-                parent.index(),
-                name.as_ptr(),
-                size.as_ptr(),
-                zone_type,
-                &mut zone_index,
-            )
-        })?;
+        Self::write_with_cell_dim(parent, data, cell_dim)
+    }
+}
+impl<'z, M: OpenMode> UpdatableNode<'z, M> for Zone<'z, M> {
+    fn item_name(data: &Self::Item) -> &str {
+        &data.name
+    }
 
-        Ok(zone_index)
+    fn index_by_name(parent: &Self::Parent, name: &str) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        Ok(parent.zone_by_name(name)?.index())
     }
 }
 impl<'z, M: OpenMode> ChildNode<'z, M> for Zone<'z, M> {
@@ -214,9 +527,18 @@ impl<'z, M: OpenMode> SiblingNode<'z, M> for Zone<'z, M> {
             zone_index,
         }
     }
+
+    fn count(parent: &Self::Parent) -> CgnsResult<i32> {
+        let mut nzones = 0;
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_nzones(parent.file().file_number(), parent.index(), &mut nzones)
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(nzones)
+    }
 }
 
-// TODO
-// impl<'z, M: OpenMode> ParentNode<'z> for Zone<'z, M> {
-//     fn n_children(&self, child_kind: Self::Children) -> CgnsResult<i32> {}
-// }
+impl<'z, M: OpenMode> ParentNode<'z, M, Grid<'z, M>> for Zone<'z, M> {}
+impl<'z, M: OpenMode> ParentNode<'z, M, FlowSolution<'z, M>> for Zone<'z, M> {}
@@ -1,9 +1,25 @@
 use super::*;
 use std::ffi::CString;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Every open (and reopen) of a file gets a new generation, see `NodeHandle`
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+fn next_generation() -> u64 {
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
+}
 
 pub struct File<'f, M: OpenMode> {
     file_number: i32,
+    /// the absolute path of the file, so `reopen` doesn't depend on the working directory
+    filename: String,
+    /// `false` once closed, e.g. if reopening it in `delete_base` / `rename_base` failed
+    is_open: bool,
+    /// changes whenever the file is reopened, so stale `NodeHandle`s can be detected
+    generation: u64,
+    /// libcgns refuses all reads for files opened with `CgnsOpenMode::Write`
+    mode: CgnsOpenMode,
     pub(crate) lib: &'f Library,
     _phantom: PhantomData<*const M>,
 }
@@ -20,6 +36,13 @@ impl<'f, M: OpenMode> File<'f, M> {
         Base::new(self, base_index)
     }
 
+    pub fn base_by_name<'b>(&'b self, name: &str) -> CgnsResult<Base<'b, M>>
+    where
+        M: OpenModeRead,
+    {
+        Base::find_by_name(self, name)
+    }
+
     pub fn bases<'b>(&'b self) -> CgnsResult<NodeIter<M, Base<'b, M>>>
     where
         M: OpenModeRead,
@@ -27,23 +50,166 @@ impl<'f, M: OpenMode> File<'f, M> {
         Base::iter(self)
     }
 
-    pub fn n_bases<'b>(&'b self) -> CgnsResult<i32>
+    pub fn n_bases(&self) -> CgnsResult<i32>
     where
         M: OpenModeRead,
-        Self: ParentNode<'b, M, Base<'b, M>>,
     {
-        self.n_children()
+        Base::count(self)
     }
 
     fn close_by_ref(&mut self) -> CgnsResult<()> {
+        // the file number might get reused for the next file we open
+        self.lib.invalidate_goto();
+        self.is_open = false;
         to_cgns_result(unsafe { cgns_bindings::cg_close(self.file_number) })
     }
 
+    /// Close and reopen the file, since libcgns only reads the node tree when opening a file.
+    /// `delete_base` and `rename_base` call this after changing the file through cgio.
+    /// Fails with `CgnsErrorKind::FileMode` unless the file was opened with `CgnsOpenMode::Modify`,
+    /// which it is reopened with again.
+    /// Note: libcgns assigns a new `file_number`, so all `CgnsPath`s and `NodeHandle`s of this file
+    /// become invalid. If opening fails, e.g. because the file was moved, the `File` stays closed
+    /// and all other calls fail until `reopen` succeeds.
+    pub fn reopen(&mut self) -> CgnsResult<()>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        self.check_reopen()?;
+        if self.is_open {
+            self.close_by_ref()?;
+        }
+        self.generation = next_generation();
+        self.file_number = Self::open_raw(&self.filename, self.mode)?;
+        self.is_open = true;
+        Ok(())
+    }
+
+    /// Make sure `reopen` can succeed before changing anything
+    fn check_reopen(&self) -> CgnsResult<()> {
+        // reopening a file opened with `CgnsOpenMode::Write` would truncate it
+        if self.mode != CgnsOpenMode::Modify {
+            return Err(CgnsError::file_mode());
+        }
+        Ok(())
+    }
+
+    /// `false` after a failed `reopen`
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Delete the base called `name` and everything below it.
+    /// Note: this shifts the index of all bases after it. The file is reopened for libcgns to notice,
+    /// see `reopen` for what that invalidates and what happens if it fails.
+    pub fn delete_base(&mut self, name: &str) -> CgnsResult<()>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        self.check_reopen()?;
+        if self.base_index_by_name(name)?.is_none() {
+            return Err(self.base_not_found(name));
+        }
+
+        {
+            let cgio = self.cgio()?;
+            let base = cgio.child(cgio.root_node(), name.as_bytes())?;
+            cgio.delete_node(cgio.root_node(), base)?;
+        }
+
+        self.reopen()
+    }
+
+    /// Rename the base called `name` to `new_name`, keeping its index and children.
+    /// Note: the file is reopened for libcgns to notice, see `reopen` for what that invalidates
+    /// and what happens if it fails.
+    pub fn rename_base(&mut self, name: &str, new_name: &str) -> CgnsResult<()>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        CgnsName::check(new_name)?;
+        self.check_reopen()?;
+        let base_index = self
+            .base_index_by_name(name)?
+            .ok_or_else(|| self.base_not_found(name))?;
+
+        {
+            let cgio = self.cgio()?;
+            // the new name has to be unique among all children of the root, not only the bases
+            if cgio.child(cgio.root_node(), new_name.as_bytes()).is_ok() {
+                return Err(CgnsError::already_exists().with_path(self.base_path(base_index)));
+            }
+            let base = cgio.child(cgio.root_node(), name.as_bytes())?;
+            cgio.set_name(cgio.root_node(), base, new_name)?;
+        }
+
+        self.reopen()
+    }
+
+    /// The index of the base called `name`, works for all open modes
+    pub(crate) fn base_index_by_name(&self, name: &str) -> CgnsResult<Option<i32>> {
+        if !self.is_readable() {
+            return self.write_only_base_index_by_name(name);
+        }
+
+        let mut n_bases = 0;
+        to_cgns_result(unsafe { cgns_bindings::cg_nbases(self.file_number, &mut n_bases) })?;
+
+        for base_index in 1..=n_bases {
+            if Base::new_unchecked(self, base_index).read_raw()?.0 == name.as_bytes() {
+                return Ok(Some(base_index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// libcgns refuses all reads in `CgnsOpenMode::Write`, so this goes through cgio. Bases can't be
+    /// replaced or deleted in that mode, so libcgns numbers them in the order they were created,
+    /// which is the order cgio lists them in. `CGNSLibraryVersion` and other children are skipped.
+    fn write_only_base_index_by_name(&self, name: &str) -> CgnsResult<Option<i32>> {
+        let cgio = self.cgio()?;
+        let mut base_index = 0;
+        for node in cgio.children(cgio.root_node())? {
+            if cgio.label(node)? != CgnsNodeLabel::Base.to_string() {
+                continue;
+            }
+            base_index += 1;
+            if cgio.name_bytes(node)? == name.as_bytes() {
+                return Ok(Some(base_index));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The error for a missing base, recording the path of the file itself (base index 0)
+    fn base_not_found(&self, name: &str) -> CgnsError {
+        CgnsError::node_not_found_by_name(&CgnsNodeLabel::Base.to_string(), name)
+            .with_path(self.base_path(0))
+    }
+
+    pub(crate) fn base_path(&self, base_index: i32) -> CgnsPath {
+        CgnsPath {
+            file_number: self.file_number,
+            base_index,
+            nodes: vec![],
+        }
+    }
+
     // make sure this `File` isn't used after we close it
     pub fn close(mut self) -> CgnsResult<()> {
-        self.close_by_ref()?;
-        std::mem::forget(self); // we don't want to call `close` twice...
-        Ok(())
+        // `drop` skips files that are already closed
+        self.close_by_ref()
+    }
+
+    /// Whether libcgns allows reads, i.e. the file wasn't opened with `CgnsOpenMode::Write`
+    pub fn is_readable(&self) -> bool {
+        self.mode != CgnsOpenMode::Write
+    }
+
+    /// The mode the file was opened with, which `reopen` keeps
+    pub fn mode(&self) -> CgnsOpenMode {
+        self.mode
     }
 
     /// exposes the cgns_bindings internal file_number (`fn`) of this file
@@ -51,6 +217,10 @@ impl<'f, M: OpenMode> File<'f, M> {
         self.file_number
     }
 
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
     pub(crate) fn open_raw(filename: &str, mode: CgnsOpenMode) -> CgnsResult<i32> {
         let filename = CString::new(filename)?;
         let mut file_number = 0;
@@ -67,46 +237,84 @@ impl<'f, M: OpenMode> File<'f, M> {
         filename: &str,
         mode: CgnsOpenMode,
     ) -> CgnsResult<File<'l, UnknownFile>> {
-        Ok(File {
-            file_number: Self::open_raw(filename, mode)?,
-            lib,
-            _phantom: Default::default(),
-        })
+        Self::open_with_mode(lib, filename, mode)
     }
 
     pub(crate) fn open_read<'l>(
         lib: &'l Library,
         filename: &str,
     ) -> CgnsResult<File<'l, ReadableFile>> {
-        Ok(File {
-            file_number: Self::open_raw(filename, CgnsOpenMode::Read)?,
-            lib,
-            _phantom: Default::default(),
-        })
+        Self::open_with_mode(lib, filename, CgnsOpenMode::Read)
     }
 
     pub(crate) fn open_write<'l>(
         lib: &'l Library,
         filename: &str,
     ) -> CgnsResult<File<'l, WriteableFile>> {
-        Ok(File {
-            file_number: Self::open_raw(filename, CgnsOpenMode::Write)?,
-            lib,
-            _phantom: Default::default(),
-        })
+        Self::open_with_mode(lib, filename, CgnsOpenMode::Write)
     }
 
     pub(crate) fn open_modify<'l>(
         lib: &'l Library,
         filename: &str,
     ) -> CgnsResult<File<'l, ModifiableFile>> {
+        Self::open_with_mode(lib, filename, CgnsOpenMode::Modify)
+    }
+
+    fn open_with_mode<'l, N: OpenMode>(
+        lib: &'l Library,
+        filename: &str,
+        mode: CgnsOpenMode,
+    ) -> CgnsResult<File<'l, N>> {
+        let file_number = Self::open_raw(filename, mode)?;
+        // libcgns opened (or created) the file, so this only fails for paths that aren't UTF-8
+        let absolute = std::fs::canonicalize(filename)
+            .ok()
+            .and_then(|path| path.to_str().map(String::from))
+            .unwrap_or_else(|| filename.to_string());
+
         Ok(File {
-            file_number: Self::open_raw(filename, CgnsOpenMode::Modify)?,
+            file_number,
+            filename: absolute,
+            is_open: true,
+            generation: next_generation(),
+            mode,
             lib,
             _phantom: Default::default(),
         })
     }
 
+    /// the CGNS version the file was written with
+    pub fn version(&self) -> CgnsResult<f32> {
+        let mut version = 0.0;
+
+        to_cgns_result(unsafe { cgns_bindings::cg_version(self.file_number, &mut version) })?;
+
+        Ok(version)
+    }
+
+    /// the precision of the floating point data in the file: 32, 64 or 0 if it is not yet known
+    pub fn precision(&self) -> CgnsResult<i32> {
+        let mut precision = 0;
+
+        to_cgns_result(unsafe { cgns_bindings::cg_precision(self.file_number, &mut precision) })?;
+
+        Ok(precision)
+    }
+
+    /// the backend the file is stored in
+    pub fn file_type(&self) -> CgnsResult<CgnsFileType> {
+        use std::convert::TryFrom;
+
+        let mut file_type = 0;
+
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_get_file_type(self.file_number, &mut file_type)
+        })?;
+
+        CgnsFileType::try_from(file_type)
+    }
+
     /// exposes the cgns_bindings internal cgio_number (`cgio_num`) of this file
     pub fn get_cgio_number(&self) -> CgnsResult<i32> {
         let mut cgio_number = 0;
@@ -125,6 +333,11 @@ impl<'f, M: OpenMode> File<'f, M> {
         Ok(root_id)
     }
 
+    /// `goto` a node in this file by its name based path, e.g. `/Base/Zone/GridCoordinates`
+    pub fn goto_path(&self, path: &str) -> CgnsResult<()> {
+        self.lib.goto_path(self.file_number, path)
+    }
+
     pub fn cgio<'s>(&'s self) -> CgnsResult<Cgio<'s, M>> {
         Cgio::from_file(self)
     }
@@ -149,8 +362,10 @@ impl<'f, M: OpenMode> File<'f, M> {
 }
 impl<'f, M: OpenMode> Drop for File<'f, M> {
     fn drop(&mut self) {
-        self.close_by_ref()
-            .expect(&format!("Failed to close {:?}", self))
+        if self.is_open {
+            self.close_by_ref()
+                .expect(&format!("Failed to close {:?}", self))
+        }
     }
 }
 impl<'f, M: OpenMode> Node for File<'f, M> {}
@@ -159,10 +374,4 @@ impl<'f, M: OpenMode> IndexableNode for File<'f, M> {
         self.file_number
     }
 }
-impl<'f, M: OpenMode> ParentNode<'f, M, Base<'f, M>> for File<'f, M> {
-    fn n_children(&self) -> CgnsResult<i32> {
-        let mut nbases = 0;
-        to_cgns_result(unsafe { cgns_bindings::cg_nbases(self.file_number, &mut nbases) })?;
-        Ok(nbases)
-    }
-}
+impl<'f, M: OpenMode> ParentNode<'f, M, Base<'f, M>> for File<'f, M> {}
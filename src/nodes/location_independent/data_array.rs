@@ -0,0 +1,425 @@
+use super::*;
+use std::{
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    mem::MaybeUninit,
+    os::raw::{c_char, c_int, c_void},
+};
+
+/// A single array of values (e.g. `CoordinateX` or `Density`) below a grid or flow solution,
+/// see the `Coordinate` and `Field` aliases.
+/// It is read and written through the `cg_array_*` functions at the position of its parent.
+pub struct DataArray<'p, M: OpenMode + 'p, P>
+where
+    P: ParentNode<'p, M, Self>,
+{
+    parent: &'p P,
+    array_index: i32,
+    _phantom: PhantomData<M>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataArrayData {
+    pub name: CgnsName,
+    /// one value per vertex or cell (depending on the location of the parent), in Fortran (i fastest) order
+    /// and in the type they are stored as
+    pub data: ArrayData,
+}
+
+/// The nodes that hold one `DataArray` per variable with a value for each vertex or cell of their zone,
+/// i.e. grids and flow solutions
+pub trait DataArrayParent<'p, M: OpenMode + 'p>:
+    ParentNode<'p, M, DataArray<'p, M, Self>> + GotoTarget<M> + BaseRefNode<M> + Sized + 'p
+{
+    /// The zone whose size the arrays have to match
+    fn zone(&self) -> &Zone<'p, M>;
+
+    /// Where the values of the arrays are located, without requiring `OpenModeRead` (see `Zone::read_unchecked`)
+    fn location_unchecked(&self) -> CgnsResult<GridLocation>;
+}
+
+/// The name, type and dimensions of an array, as returned by `cg_array_info`
+struct ArrayInfo {
+    name: Vec<u8>,
+    data_type: DataType,
+    dims: Vec<i64>,
+}
+/// Read the info of the array with index `array_index` below the current `goto` position
+fn read_array_info(array_index: i32) -> CgnsResult<ArrayInfo> {
+    use std::convert::TryFrom;
+
+    let mut name = [MaybeUninit::<c_char>::uninit(); 33];
+    let mut data_type = 0;
+    let mut n_dims: c_int = 0;
+    let mut dims: [cgns_bindings::cgsize_t; 12] = [0; 12];
+
+    to_cgns_result(unsafe {
+        cgns_bindings::cg_array_info(
+            array_index,
+            name.as_mut_ptr() as *mut c_char,
+            &mut data_type,
+            &mut n_dims,
+            dims.as_mut_ptr(),
+        )
+    })?;
+
+    let n_dims = (n_dims.max(0) as usize).min(dims.len());
+    // `cgsize_t` is either `i32` or `i64`, depending on how libcgns was built
+    #[allow(clippy::useless_conversion)]
+    let dims = dims[..n_dims].iter().map(|&dim| i64::from(dim)).collect();
+
+    Ok(ArrayInfo {
+        name: unsafe { CStr::from_ptr(name.as_ptr() as *const c_char) }
+            .to_bytes()
+            .to_vec(),
+        data_type: DataType::try_from(data_type)?,
+        dims,
+    })
+}
+impl ArrayInfo {
+    fn len(&self) -> CgnsResult<usize> {
+        use std::convert::TryFrom;
+
+        self.dims.iter().try_fold(1usize, |len, &dim| {
+            len.checked_mul(usize::try_from(dim.max(0))?)
+                .ok_or_else(CgnsError::out_of_bounds)
+        })
+    }
+}
+
+impl<'p, M: OpenMode, P> DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+{
+    fn read_info(&self) -> CgnsResult<ArrayInfo> {
+        self.parent.goto()?;
+        read_array_info(self.array_index).map_err(|err| err.with_path(self.path()))
+    }
+
+    /// The type the values are stored as in the file
+    pub fn data_type(&self) -> CgnsResult<DataType>
+    where
+        M: OpenModeRead,
+    {
+        Ok(self.read_info()?.data_type)
+    }
+
+    /// The number of values of this array (one per vertex or cell, depending on the location of the parent)
+    pub fn data_len(&self) -> CgnsResult<usize>
+    where
+        M: OpenModeRead,
+    {
+        self.read_info()?.len()
+    }
+
+    /// Like `read`, but decodes a name that isn't valid UTF-8 as Latin-1 instead of failing
+    /// (see `CgnsName::from_bytes_lossy`)
+    pub fn read_lossy(&self) -> CgnsResult<DataArrayData>
+    where
+        M: OpenModeRead,
+    {
+        Ok(DataArrayData {
+            name: CgnsName::from_bytes_lossy(&self.read_info()?.name),
+            data: self.read_data_unchecked()?,
+        })
+    }
+
+    /// Read the values of this array, converted to `T`
+    pub fn read_vec<T: CgnsScalar>(&self) -> CgnsResult<Vec<T>>
+    where
+        M: OpenModeRead,
+    {
+        self.read_vec_unchecked()
+    }
+
+    fn read_vec_unchecked<T: CgnsScalar>(&self) -> CgnsResult<Vec<T>> {
+        let len = self.read_info()?.len()?;
+        let mut data = Vec::with_capacity(len);
+
+        self.read_into_unchecked(&mut data.spare_capacity_mut()[..len], len)?;
+        // Safety: `read_into_unchecked` initialized the first `len` values
+        unsafe { data.set_len(len) };
+
+        Ok(data)
+    }
+
+    fn read_data_unchecked(&self) -> CgnsResult<ArrayData> {
+        Ok(match self.read_info()?.data_type {
+            DataType::Integer => ArrayData::Integer(self.read_vec_unchecked()?),
+            DataType::LongInteger => ArrayData::LongInteger(self.read_vec_unchecked()?),
+            DataType::RealSingle => ArrayData::RealSingle(self.read_vec_unchecked()?),
+            DataType::RealDouble => ArrayData::RealDouble(self.read_vec_unchecked()?),
+            DataType::Character => ArrayData::Character(self.read_vec_unchecked()?),
+            _ => return Err(CgnsError::unsupported().with_path(self.path())),
+        })
+    }
+
+    /// Read the values of this array into `buffer`, which has to hold exactly `data_len` values
+    pub fn read_into<T: CgnsScalar>(&self, buffer: &mut [T]) -> CgnsResult<()>
+    where
+        M: OpenModeRead,
+    {
+        // Safety: we only ever write initialized values to the buffer
+        self.read_into_uninit(unsafe { &mut *(buffer as *mut [T] as *mut [MaybeUninit<T>]) })?;
+        Ok(())
+    }
+
+    /// Read the values of this array into `buffer`, which has to hold exactly `data_len` values.
+    /// Returns the now initialized buffer.
+    pub fn read_into_uninit<'b, T: CgnsScalar>(
+        &self,
+        buffer: &'b mut [MaybeUninit<T>],
+    ) -> CgnsResult<&'b mut [T]>
+    where
+        M: OpenModeRead,
+    {
+        let len = self.read_info()?.len()?;
+        self.read_into_unchecked(buffer, len)
+    }
+
+    /// Read all `len` values into `buffer` after checking its length
+    fn read_into_unchecked<'b, T: CgnsScalar>(
+        &self,
+        buffer: &'b mut [MaybeUninit<T>],
+        len: usize,
+    ) -> CgnsResult<&'b mut [T]> {
+        if buffer.len() != len {
+            return Err(CgnsError::dimension_mismatch().with_path(self.path()));
+        }
+
+        self.parent.goto()?;
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_array_read_as(
+                self.array_index,
+                T::DATA_TYPE.into(),
+                buffer.as_mut_ptr() as *mut c_void,
+            )
+        })
+        .map_err(|err| err.with_path(self.path()))?;
+
+        // Safety: `cg_array_read_as` initialized the whole array we checked the buffer length against
+        Ok(unsafe { &mut *(buffer as *mut [MaybeUninit<T>] as *mut [T]) })
+    }
+
+    /// Write an array without copying `data`, which has to hold one value per vertex or cell of the zone.
+    /// Returns the index of the new (or overwritten) array.
+    /// Note: this needs to read the size of the zone, which fails for files opened with `CgnsOpenMode::Write`
+    pub fn write_slice<T: CgnsScalar>(parent: &mut P, name: &str, data: &[T]) -> CgnsResult<i32>
+    where
+        M: OpenModeWrite,
+    {
+        let zone_size = parent.zone().read_unchecked()?.size;
+        let location = parent.location_unchecked()?;
+        Self::write_sized(parent, name, data, &zone_size, location)?;
+        Self::index_by_name(parent, name)
+    }
+
+    /// Write `data` in the type it holds, see `write_sized`
+    pub(crate) fn write_data(
+        parent: &P,
+        name: &str,
+        data: &ArrayData,
+        zone_size: &ZoneSize,
+        location: GridLocation,
+    ) -> CgnsResult<()> {
+        match data {
+            ArrayData::Integer(data) => Self::write_sized(parent, name, data, zone_size, location),
+            ArrayData::LongInteger(data) => {
+                Self::write_sized(parent, name, data, zone_size, location)
+            }
+            ArrayData::RealSingle(data) => {
+                Self::write_sized(parent, name, data, zone_size, location)
+            }
+            ArrayData::RealDouble(data) => {
+                Self::write_sized(parent, name, data, zone_size, location)
+            }
+            ArrayData::Character(data) => {
+                Self::write_sized(parent, name, data, zone_size, location)
+            }
+        }
+    }
+
+    /// Write `data` after checking its length against `zone_size` and the `location` of `parent`.
+    /// Both are passed in, so this works for files opened with `CgnsOpenMode::Write`. For the same reason
+    /// it doesn't return the index of the array, since finding it is a read.
+    pub(crate) fn write_sized<T: CgnsScalar>(
+        parent: &P,
+        name: &str,
+        data: &[T],
+        zone_size: &ZoneSize,
+        location: GridLocation,
+    ) -> CgnsResult<()> {
+        CgnsName::check(name).map_err(|err| err.with_path(parent.path()))?;
+        let dims = zone_size.dims(location)?;
+        if data.len() != zone_size.len(location)? {
+            return Err(CgnsError::dimension_mismatch().with_path(parent.path()));
+        }
+
+        let name = CString::new(name)?;
+        let dims = to_cgsize(&dims)?;
+
+        parent.goto()?;
+        to_cgns_result(unsafe {
+            cgns_bindings::cg_array_write(
+                name.as_ptr(),
+                T::DATA_TYPE.into(),
+                dims.len() as c_int,
+                dims.as_ptr(),
+                data.as_ptr() as *const c_void,
+            )
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
+        // overwriting an existing array frees the old node
+        parent.lib().invalidate_goto();
+
+        Ok(())
+    }
+
+    /// The index of the array called `name` below `parent`
+    fn index_by_name(parent: &P, name: &str) -> CgnsResult<i32> {
+        for array_index in 1..=Self::count_unchecked(parent)? {
+            // `count_unchecked` moved to `parent`, which reading the info doesn't change
+            let info = read_array_info(array_index).map_err(|err| err.with_path(parent.path()))?;
+            if info.name == name.as_bytes() {
+                return Ok(array_index);
+            }
+        }
+
+        Err(
+            CgnsError::node_not_found_by_name(&CgnsNodeLabel::DataArray.to_string(), name)
+                .with_path(parent.path()),
+        )
+    }
+
+    fn count_unchecked(parent: &P) -> CgnsResult<i32> {
+        let mut n_arrays = 0;
+
+        parent.goto()?;
+        to_cgns_result(unsafe { cgns_bindings::cg_narrays(&mut n_arrays) })
+            .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(n_arrays)
+    }
+}
+#[cfg(feature = "ndarray")]
+impl<'p, M: OpenMode, P> DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+{
+    /// Read the array of a structured zone as an array indexed by `[i, j, k]`
+    pub fn read_array<T: CgnsScalar>(&self) -> CgnsResult<ndarray::Array3<T>>
+    where
+        M: OpenModeRead,
+    {
+        let zone_size = self.parent.zone().read()?.size;
+        zone_size
+            .array_from_data(self.parent.location_unchecked()?, self.read_vec()?)
+            .map_err(|err| err.with_path(self.path()))
+    }
+
+    /// Write an array of a structured zone from an array indexed by `[i, j, k]`
+    pub fn write_array<T: CgnsScalar>(
+        parent: &mut P,
+        name: &str,
+        data: ndarray::ArrayView3<T>,
+    ) -> CgnsResult<i32>
+    where
+        M: OpenModeWrite,
+    {
+        let zone_size = parent.zone().read_unchecked()?.size;
+        let location = parent.location_unchecked()?;
+        let data = zone_size
+            .data_from_array(location, data)
+            .map_err(|err| err.with_path(parent.path()))?;
+        Self::write_sized(parent, name, &data, &zone_size, location)?;
+        Self::index_by_name(parent, name)
+    }
+}
+impl<'p, M: OpenMode, P> Node for DataArray<'p, M, P> where P: ParentNode<'p, M, Self> {}
+impl<'p, M: OpenMode, P> ChildNode<'p, M> for DataArray<'p, M, P>
+where
+    P: ParentNode<'p, M, Self>,
+{
+    type Parent = P;
+    fn parent(&self) -> &Self::Parent {
+        self.parent
+    }
+}
+impl<'p, M: OpenMode, P> LabeledNode for DataArray<'p, M, P>
+where
+    P: ParentNode<'p, M, Self>,
+{
+    const NODE_LABEL: CgnsNodeLabel = CgnsNodeLabel::DataArray;
+}
+impl<'p, M: OpenMode, P> NamedNode<M> for DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+    M: OpenModeRead,
+{
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        Ok(self.read_info()?.name)
+    }
+}
+impl<'p, M: OpenMode, P> GotoTarget<M> for DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+{
+    fn path(&self) -> CgnsPath {
+        let mut path = self.parent.path();
+        path.nodes
+            .push((CgnsNodeLabel::DataArray, self.array_index));
+        path
+    }
+}
+impl<'p, M: OpenMode, P> RwNode<'p, M> for DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+{
+    type Item = DataArrayData;
+    fn read(&self) -> CgnsResult<Self::Item> {
+        Ok(DataArrayData {
+            name: CgnsName::new_unchecked(String::from_utf8(self.read_info()?.name)?),
+            data: self.read_data_unchecked()?,
+        })
+    }
+    /// Note: this needs to read the size of the zone, which fails for files opened with `CgnsOpenMode::Write`
+    fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
+        let zone_size = parent.zone().read_unchecked()?.size;
+        let location = parent.location_unchecked()?;
+        Self::write_data(parent, &data.name, &data.data, &zone_size, location)?;
+        Self::index_by_name(parent, &data.name)
+    }
+}
+impl<'p, M: OpenMode, P> BaseRefNode<M> for DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+{
+    fn base(&self) -> &Base<'_, M> {
+        self.parent.base()
+    }
+}
+impl<'p, M: OpenMode, P> IndexableNode for DataArray<'p, M, P>
+where
+    P: ParentNode<'p, M, Self>,
+{
+    fn index(&self) -> i32 {
+        self.array_index
+    }
+}
+impl<'p, M: OpenMode, P> SiblingNode<'p, M> for DataArray<'p, M, P>
+where
+    P: DataArrayParent<'p, M>,
+{
+    fn new_unchecked(parent: &'p Self::Parent, array_index: i32) -> Self {
+        DataArray {
+            parent,
+            array_index,
+            _phantom: Default::default(),
+        }
+    }
+
+    fn count(parent: &Self::Parent) -> CgnsResult<i32> {
+        Self::count_unchecked(parent)
+    }
+}
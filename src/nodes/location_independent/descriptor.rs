@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
@@ -14,10 +15,77 @@ where
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DescriptorData {
-    pub name: String,
+    pub name: CgnsName,
     pub value: String,
 }
+
+/// A descriptor as stored in the file, without requiring valid UTF-8
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct DescriptorBytes {
+    pub name: Vec<u8>,
+    pub value: Vec<u8>,
+}
+impl DescriptorBytes {
+    /// Decode the name and value as UTF-8, falling back to Latin-1 for text that isn't valid UTF-8
+    /// (see `CgnsName::from_bytes_lossy` for names)
+    pub fn decode_lossy(&self) -> DescriptorData {
+        DescriptorData {
+            name: CgnsName::from_bytes_lossy(&self.name),
+            value: decode_lossy(&self.value),
+        }
+    }
+}
+impl<'p, P, M: OpenMode> Descriptor<'p, M, P>
+where
+    P: ParentNode<'p, M, Self> + GotoTarget<M> + BaseRefNode<M>,
+{
+    pub fn read_bytes(&self) -> CgnsResult<DescriptorBytes>
+    where
+        M: OpenModeRead,
+    {
+        self.parent.goto()?;
+        read_descriptor_bytes(self.descriptor_index).map_err(|err| err.with_path(self.path()))
+    }
+
+    /// Like `read`, but decodes text that isn't valid UTF-8 as Latin-1 instead of failing
+    pub fn read_lossy(&self) -> CgnsResult<DescriptorData>
+    where
+        M: OpenModeRead,
+    {
+        Ok(self.read_bytes()?.decode_lossy())
+    }
+
+    /// Replace the value of this descriptor, its name and index stay the same
+    pub fn update_value(&self, value: &str) -> CgnsResult<()>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        let name = CString::new(self.read_bytes()?.name)?;
+        let value = CString::new(value)?;
+
+        self.parent.goto()?;
+
+        to_cgns_result(unsafe { cgns_bindings::cg_descriptor_write(name.as_ptr(), value.as_ptr()) })
+            .map_err(|err| err.with_path(self.path()))
+    }
+
+    /// Delete this descriptor.
+    /// Note: this shifts the index of all descriptors after it
+    pub fn remove(self) -> CgnsResult<()>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        let name = self.read_bytes()?.name;
+        let path = self.path();
+
+        self.parent.goto()?;
+        self.lib()
+            .delete_node(name)
+            .map_err(|err| err.with_path(path))
+    }
+}
 impl<'p, P, M: OpenMode> Node for Descriptor<'p, M, P> where P: ParentNode<'p, M, Self> {}
 impl<'p, P, M: OpenMode> ChildNode<'p, M> for Descriptor<'p, M, P>
 where
@@ -28,19 +96,9 @@ where
         self.parent
     }
 }
-impl<'p, N, M: OpenMode> ParentNode<'p, M, Descriptor<'p, M, Self>> for N
-where
-    N: Node + GotoTarget<M> + BaseRefNode<M>,
+impl<'p, N, M: OpenMode> ParentNode<'p, M, Descriptor<'p, M, Self>> for N where
+    N: Node + GotoTarget<M> + BaseRefNode<M>
 {
-    fn n_children(&self) -> CgnsResult<i32> {
-        self.goto()?;
-
-        let mut n_descriptors = 0;
-
-        to_cgns_result(unsafe { cgns_bindings::cg_ndescriptors(&mut n_descriptors) })?;
-
-        Ok(n_descriptors)
-    }
 }
 impl<'p, M: OpenMode, P> LabeledNode for Descriptor<'p, M, P>
 where
@@ -53,8 +111,9 @@ where
     P: BaseRefNode<M> + GotoTarget<M>,
     M: OpenModeRead,
 {
-    fn name(&self) -> CgnsResult<String> {
-        Ok(String::from(&self.read()?.name))
+    /// Note: libcgns can't read only the name, so this copies the text as well
+    fn name_bytes(&self) -> CgnsResult<Vec<u8>> {
+        Ok(self.read_bytes()?.name)
     }
 }
 impl<'p, P, M: OpenMode> GotoTarget<M> for Descriptor<'p, M, P>
@@ -77,7 +136,7 @@ where
 }
 impl<'p, P, M: OpenMode> SiblingNode<'p, M> for Descriptor<'p, M, P>
 where
-    P: ParentNode<'p, M, Self>,
+    P: ParentNode<'p, M, Self> + GotoTarget<M> + BaseRefNode<M>,
 {
     fn new_unchecked(parent: &'p Self::Parent, descriptor_index: i32) -> Self {
         Descriptor {
@@ -86,6 +145,17 @@ where
             _phantom: Default::default(),
         }
     }
+
+    fn count(parent: &Self::Parent) -> CgnsResult<i32> {
+        parent.goto()?;
+
+        let mut n_descriptors = 0;
+
+        to_cgns_result(unsafe { cgns_bindings::cg_ndescriptors(&mut n_descriptors) })
+            .map_err(|err| err.with_path(parent.path()))?;
+
+        Ok(n_descriptors)
+    }
 }
 impl<'p, P, M: OpenMode> RwNode<'p, M> for Descriptor<'p, M, P>
 where
@@ -95,45 +165,56 @@ where
     fn read(&self) -> CgnsResult<Self::Item> {
         self.parent().goto()?;
 
-        let mut name = [MaybeUninit::<c_char>::uninit(); 33];
-        let mut value = MaybeUninit::<*mut c_char>::uninit();
-
-        to_cgns_result(unsafe {
-            cgns_bindings::cg_descriptor_read(
-                self.index(),
-                name.as_mut_ptr() as *mut c_char,
-                value.as_mut_ptr(),
-            )
-        })?;
-
-        let descriptor_data = DescriptorData {
-            name: unsafe { CStr::from_ptr(name.as_ptr() as *const c_char) }
-                .to_str()?
-                .to_string(),
-            value: unsafe { CStr::from_ptr(value.assume_init()) }
-                .to_str()?
-                .to_string(),
-        };
-
-        to_cgns_result(unsafe { cgns_bindings::cg_free(value.assume_init() as *mut c_void) })?;
-
-        Ok(descriptor_data)
+        read_descriptor(self.index()).map_err(|err| err.with_path(self.path()))
     }
     fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
         parent.goto()?;
 
-        let name = CString::new(data.name.clone())?;
+        let name = CString::new(data.name.as_str())?;
         let value = CString::new(data.value.clone())?;
 
         parent.goto()?;
 
         to_cgns_result(unsafe {
             cgns_bindings::cg_descriptor_write(name.as_ptr(), value.as_ptr())
-        })?;
+        })
+        .map_err(|err| err.with_path(parent.path()))?;
 
         Ok(-1)
     }
 }
+impl<'p, P, M: OpenMode> UpdatableNode<'p, M> for Descriptor<'p, M, P>
+where
+    P: ParentNode<'p, M, Self> + GotoTarget<M> + BaseRefNode<M>,
+{
+    fn item_name(data: &Self::Item) -> &str {
+        &data.name
+    }
+
+    fn index_by_name(parent: &Self::Parent, name: &str) -> CgnsResult<i32>
+    where
+        M: OpenModeRead,
+    {
+        parent.goto()?;
+
+        let mut n_descriptors = 0;
+
+        to_cgns_result(unsafe { cgns_bindings::cg_ndescriptors(&mut n_descriptors) })
+            .map_err(|err| err.with_path(parent.path()))?;
+
+        for descriptor_index in 1..=n_descriptors {
+            // compare the raw names, so descriptors that aren't valid UTF-8 can't fail the search
+            if read_descriptor_bytes(descriptor_index)?.name == name.as_bytes() {
+                return Ok(descriptor_index);
+            }
+        }
+
+        Err(
+            CgnsError::node_not_found_by_name(&CgnsNodeLabel::Descriptor.to_string(), name)
+                .with_path(parent.path()),
+        )
+    }
+}
 impl<'p, P, M: OpenMode> BaseRefNode<M> for Descriptor<'p, M, P>
 where
     P: BaseRefNode<M> + GotoTarget<M>,
@@ -143,6 +224,40 @@ where
     }
 }
 
+/// Read the descriptor with index `descriptor_index` below the current `goto` position
+fn read_descriptor(descriptor_index: i32) -> CgnsResult<DescriptorData> {
+    let DescriptorBytes { name, value } = read_descriptor_bytes(descriptor_index)?;
+
+    Ok(DescriptorData {
+        name: CgnsName::new_unchecked(String::from_utf8(name)?),
+        value: String::from_utf8(value)?,
+    })
+}
+
+fn read_descriptor_bytes(descriptor_index: i32) -> CgnsResult<DescriptorBytes> {
+    let mut name = [MaybeUninit::<c_char>::uninit(); 33];
+    let mut value = MaybeUninit::<*mut c_char>::uninit();
+
+    to_cgns_result(unsafe {
+        cgns_bindings::cg_descriptor_read(
+            descriptor_index,
+            name.as_mut_ptr() as *mut c_char,
+            value.as_mut_ptr(),
+        )
+    })?;
+
+    let value_ptr = unsafe { value.assume_init() };
+
+    let name = unsafe { CStr::from_ptr(name.as_ptr() as *const c_char) }
+        .to_bytes()
+        .to_vec();
+    let value = unsafe { CStr::from_ptr(value_ptr) }.to_bytes().to_vec();
+
+    to_cgns_result(unsafe { cgns_bindings::cg_free(value_ptr as *mut c_void) })?;
+
+    Ok(DescriptorBytes { name, value })
+}
+
 pub trait DescriptorParent<'p, M: OpenMode + 'p>:
     ParentNode<'p, M, Descriptor<'p, M, Self>> + 'p + Sized + GotoTarget<M> + BaseRefNode<M>
 {
@@ -152,6 +267,12 @@ pub trait DescriptorParent<'p, M: OpenMode + 'p>:
     {
         Descriptor::new(self, descriptor_index)?.read()
     }
+    fn descriptor_by_name(&'p self, name: &str) -> CgnsResult<Descriptor<'p, M, Self>>
+    where
+        M: OpenModeRead,
+    {
+        Descriptor::find_by_name(self, name)
+    }
     fn set_descriptor(&mut self, descriptor_data: &DescriptorData) -> CgnsResult<()>
     where
         M: OpenModeWrite,
@@ -159,7 +280,28 @@ pub trait DescriptorParent<'p, M: OpenMode + 'p>:
         Descriptor::write(self, descriptor_data)?;
         Ok(())
     }
-    fn iter_descriptors(&'p mut self) -> CgnsResult<NodeIter<'p, M, Descriptor<'p, M, Self>>>
+    /// Overwrite the value of the existing descriptor with the same name, returns its index
+    fn update_descriptor(&mut self, descriptor_data: &DescriptorData) -> CgnsResult<i32>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        Descriptor::update(self, descriptor_data)
+    }
+    /// Delete the descriptor called `name`, other children with that name are left alone.
+    /// Note: this shifts the index of all descriptors after it
+    fn delete_descriptor(&mut self, name: &str) -> CgnsResult<()>
+    where
+        M: OpenModeRead + OpenModeWrite,
+    {
+        // `cg_delete_node` deletes any child called `name`, so make sure it is a descriptor
+        Descriptor::index_by_name(self, name)?;
+
+        self.goto()?;
+        self.lib()
+            .delete_node(name)
+            .map_err(|err| err.with_path(self.path()))
+    }
+    fn iter_descriptors(&'p self) -> CgnsResult<NodeIter<'p, M, Descriptor<'p, M, Self>>>
     where
         M: OpenModeRead,
     {
@@ -169,7 +311,34 @@ pub trait DescriptorParent<'p, M: OpenMode + 'p>:
     where
         M: OpenModeRead,
     {
-        self.n_children()
+        Descriptor::count(self)
+    }
+    /// All descriptors of this node as a name -> value map
+    fn descriptors_map(&'p self) -> CgnsResult<HashMap<String, String>>
+    where
+        M: OpenModeRead,
+    {
+        Descriptor::iter(self)?
+            .map(|descriptor| {
+                descriptor
+                    .read()
+                    .map(|data| (data.name.into_string(), data.value))
+            })
+            .collect()
+    }
+
+    /// Like `descriptors_map`, but decodes text that isn't valid UTF-8 as Latin-1 instead of failing
+    fn descriptors_map_lossy(&'p self) -> CgnsResult<HashMap<String, String>>
+    where
+        M: OpenModeRead,
+    {
+        Descriptor::iter(self)?
+            .map(|descriptor| {
+                descriptor
+                    .read_lossy()
+                    .map(|data| (data.name.into_string(), data.value))
+            })
+            .collect()
     }
 }
 impl<'p, M: OpenMode + 'p, N> DescriptorParent<'p, M> for N where
@@ -1,7 +1,9 @@
 use super::*;
 
+pub mod data_array;
 pub mod descriptor;
 pub mod ordinal;
 
+pub use data_array::*;
 pub use descriptor::*;
 pub use ordinal::*;
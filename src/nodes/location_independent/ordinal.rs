@@ -9,6 +9,8 @@ where
     _phantom: PhantomData<M>,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrdinalData(pub i32);
 impl From<i32> for OrdinalData {
     fn from(data: i32) -> Self {
@@ -30,20 +32,13 @@ where
         self.parent
     }
 }
-impl<'p, N, M: OpenMode> ParentNode<'p, M, Ordinal<'p, M, Self>> for N
-where
-    N: Node + GotoTarget<M> + BaseRefNode<M>,
+impl<'p, N, M: OpenMode> ParentNode<'p, M, Ordinal<'p, M, Self>> for N where
+    N: Node + GotoTarget<M> + BaseRefNode<M>
 {
-    fn n_children(&self) -> CgnsResult<i32>
-    where
-        Ordinal<'p, M, Self>: SiblingNode<'p, M>,
-    {
-        unreachable!()
-    }
 }
 impl<'p, M: OpenMode, P> OnlyChildNode<'p, M> for Ordinal<'p, M, P>
 where
-    P: ParentNode<'p, M, Self>,
+    P: ParentNode<'p, M, Self> + GotoTarget<M> + BaseRefNode<M>,
 {
     fn new(parent: &'p Self::Parent) -> Self {
         Self {
@@ -51,6 +46,10 @@ where
             _phantom: Default::default(),
         }
     }
+
+    fn exists(&self) -> CgnsResult<bool> {
+        self.lib().child_exists(&self.parent.path(), "Ordinal")
+    }
 }
 impl<'p, M: OpenMode, P> RwNode<'p, M> for Ordinal<'p, M, P>
 where
@@ -60,12 +59,14 @@ where
     fn read(&self) -> CgnsResult<Self::Item> {
         self.parent().goto()?;
         let mut ordinal = 0;
-        to_cgns_result(unsafe { cgns_bindings::cg_ordinal_read(&mut ordinal) })?;
+        to_cgns_result(unsafe { cgns_bindings::cg_ordinal_read(&mut ordinal) })
+            .map_err(|err| err.with_path(self.path()))?;
         Ok(OrdinalData(ordinal))
     }
     fn write(parent: &mut Self::Parent, data: &Self::Item) -> CgnsResult<i32> {
         parent.goto()?;
-        to_cgns_result(unsafe { cgns_bindings::cg_ordinal_write(data.0) })?;
+        to_cgns_result(unsafe { cgns_bindings::cg_ordinal_write(data.0) })
+            .map_err(|err| err.with_path(parent.path()))?;
         Ok(0)
     }
 }
@@ -94,6 +95,12 @@ where
 pub trait OrdinalParent<'p, M: OpenMode + 'p>:
     ParentNode<'p, M, Ordinal<'p, M, Self>> + 'p + Sized + GotoTarget<M> + BaseRefNode<M>
 {
+    fn has_ordinal(&'p self) -> CgnsResult<bool>
+    where
+        M: OpenModeRead,
+    {
+        Ordinal::new(self).exists()
+    }
     fn get_ordinal(&'p self) -> CgnsResult<OrdinalData>
     where
         M: OpenModeRead,
@@ -0,0 +1,393 @@
+//! An owned, in-memory model of a whole CGNS file.
+//! Nodes this crate doesn't model below a base (boundary conditions, connectivity, families, elements etc.)
+//! are kept as raw cgio nodes in `BaseTree::other_nodes`, so `write_tree` copies them through unchanged.
+use super::*;
+
+/// The labels of the children the tree models, by the label of their parent.
+/// All other children are kept as `OtherNode`s.
+const MODELED_CHILDREN: &[(&str, &[&str])] = &[
+    (
+        "CGNSBase_t",
+        &["Zone_t", "Descriptor_t", "SimulationType_t", "Ordinal_t"],
+    ),
+    (
+        "Zone_t",
+        &[
+            "ZoneType_t",
+            "Ordinal_t",
+            "Descriptor_t",
+            "GridCoordinates_t",
+            "FlowSolution_t",
+        ],
+    ),
+    ("GridCoordinates_t", &["DataArray_t"]),
+    ("FlowSolution_t", &["DataArray_t", "GridLocation_t"]),
+];
+
+/// A node as stored in the file, with all of its children
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawNode {
+    pub name: Vec<u8>,
+    pub label: String,
+    /// the cgio data type, e.g. `R8`, `C1` or `MT` for nodes without data
+    pub data_type: String,
+    pub dimensions: Vec<i64>,
+    /// the data in the byte order of this machine
+    pub data: Vec<u8>,
+    pub children: Vec<RawNode>,
+}
+impl RawNode {
+    /// Read `node` and everything below it. Links are followed, so the copy holds the linked nodes.
+    pub fn read<M: OpenMode>(cgio: &Cgio<M>, node: CgioNode) -> CgnsResult<Self> {
+        Ok(RawNode {
+            name: cgio.name_bytes(node)?,
+            label: cgio.label(node)?,
+            data_type: cgio.data_type(node)?,
+            dimensions: cgio.dimensions(node)?,
+            data: cgio.read_all_data(node)?,
+            children: cgio
+                .children(node)?
+                .into_iter()
+                .map(|child| RawNode::read(cgio, child))
+                .collect::<CgnsResult<_>>()?,
+        })
+    }
+
+    /// The data of an integer (`I4` or `I8`) node as `i64`, independent of how libcgns was built,
+    /// e.g. for `PointRange` (see `IndexRange::from_indices`), `PointList` or connectivity nodes
+    pub fn indices(&self) -> CgnsResult<Vec<i64>> {
+        match self.data_type.as_str() {
+            "I4" => Ok(self
+                .data
+                .chunks_exact(4)
+                .map(|bytes| {
+                    let mut value = [0; 4];
+                    value.copy_from_slice(bytes);
+                    i64::from(i32::from_ne_bytes(value))
+                })
+                .collect()),
+            "I8" => Ok(self
+                .data
+                .chunks_exact(8)
+                .map(|bytes| {
+                    let mut value = [0; 8];
+                    value.copy_from_slice(bytes);
+                    i64::from_ne_bytes(value)
+                })
+                .collect()),
+            _ => Err(CgnsError::unsupported()),
+        }
+    }
+
+    /// Create a copy of this node and everything below it as a child of `parent`
+    pub fn write<M: OpenModeWrite>(&self, cgio: &Cgio<M>, parent: CgioNode) -> CgnsResult<()> {
+        let node = cgio.create_node(
+            parent,
+            &self.name,
+            &self.label,
+            &self.data_type,
+            &self.dimensions,
+            &self.data,
+        )?;
+        for child in &self.children {
+            child.write(cgio, node)?;
+        }
+        Ok(())
+    }
+}
+
+/// A subtree below a base that the tree doesn't model
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OtherNode {
+    /// the names of the nodes from the base (exclusive) down to the parent of `node`,
+    /// e.g. `[zone]` for the `ZoneBC` of a zone
+    pub parent: Vec<Vec<u8>>,
+    pub node: RawNode,
+}
+
+/// Collect the children of `node` that the tree doesn't model, and those of its modeled descendants
+fn collect_other_nodes<M: OpenMode>(
+    cgio: &Cgio<M>,
+    node: CgioNode,
+    label: &str,
+    parent: &mut Vec<Vec<u8>>,
+    other_nodes: &mut Vec<OtherNode>,
+) -> CgnsResult<()> {
+    let modeled = MODELED_CHILDREN
+        .iter()
+        .find(|(parent_label, _)| *parent_label == label)
+        .map_or(&[][..], |(_, children)| *children);
+
+    for child in cgio.children(node)? {
+        let child_label = cgio.label(child)?;
+        if modeled.contains(&child_label.as_str()) {
+            parent.push(cgio.name_bytes(child)?);
+            collect_other_nodes(cgio, child, &child_label, parent, other_nodes)?;
+            parent.pop();
+        } else {
+            other_nodes.push(OtherNode {
+                parent: parent.clone(),
+                node: RawNode::read(cgio, child)?,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The cgio node of the base called `name`
+fn find_base_node<M: OpenMode>(cgio: &Cgio<M>, name: &[u8]) -> CgnsResult<CgioNode> {
+    for node in cgio.children(cgio.root_node())? {
+        if cgio.label(node)? == CgnsNodeLabel::Base.to_string() && cgio.name_bytes(node)? == name {
+            return Ok(node);
+        }
+    }
+    Err(CgnsError::node_not_found_by_name(
+        &CgnsNodeLabel::Base.to_string(),
+        &decode_lossy(name),
+    ))
+}
+
+#[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CgnsTree {
+    pub bases: Vec<BaseTree>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseTree {
+    pub data: BaseData,
+    pub simulation_type: Option<SimulationTypeData>,
+    pub ordinal: Option<OrdinalData>,
+    pub descriptors: Vec<DescriptorData>,
+    pub zones: Vec<ZoneTree>,
+    /// everything below the base that the other fields don't hold, e.g. boundary conditions or families
+    pub other_nodes: Vec<OtherNode>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoneTree {
+    pub data: ZoneData,
+    pub ordinal: Option<OrdinalData>,
+    pub descriptors: Vec<DescriptorData>,
+    pub grids: Vec<GridTree>,
+    pub flow_solutions: Vec<FlowSolutionTree>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridTree {
+    pub data: GridData,
+    pub coordinates: Vec<CoordinateData>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlowSolutionTree {
+    pub data: FlowSolutionData,
+    pub fields: Vec<FieldData>,
+}
+
+impl<'f, M: OpenMode + 'f> File<'f, M> {
+    /// Read all bases of this file (and everything below them) into memory.
+    /// Nodes the tree doesn't model are read as raw cgio nodes, see `BaseTree::other_nodes`.
+    pub fn load_tree(&self) -> CgnsResult<CgnsTree>
+    where
+        M: OpenModeRead,
+    {
+        Ok(CgnsTree {
+            bases: self
+                .bases()?
+                .map(|base| BaseTree::load(&base))
+                .collect::<CgnsResult<_>>()?,
+        })
+    }
+
+    /// Write all bases of `tree` to this file.
+    /// The `other_nodes` of a base are copied with cgio after everything else, libcgns only
+    /// sees them after the file is reopened.
+    /// Array lengths are checked against the zone sizes in `tree`, so this also works for files
+    /// opened with `CgnsOpenMode::Write`.
+    pub fn write_tree(&mut self, tree: &CgnsTree) -> CgnsResult<()>
+    where
+        M: OpenModeWrite,
+    {
+        for base in &tree.bases {
+            base.write(self)?;
+        }
+        Ok(())
+    }
+}
+
+impl BaseTree {
+    fn load<M: OpenModeRead>(base: &Base<M>) -> CgnsResult<Self> {
+        Ok(BaseTree {
+            data: base.read()?,
+            simulation_type: if base.has_simulation_type()? {
+                Some(SimulationType::new(base).read()?)
+            } else {
+                None
+            },
+            ordinal: if base.has_ordinal()? {
+                Some(base.get_ordinal()?)
+            } else {
+                None
+            },
+            descriptors: base
+                .iter_descriptors()?
+                .map(|descriptor| descriptor.read())
+                .collect::<CgnsResult<_>>()?,
+            zones: base
+                .zones()?
+                .map(|zone| ZoneTree::load(&zone))
+                .collect::<CgnsResult<_>>()?,
+            other_nodes: Self::load_other_nodes(base).map_err(|err| err.with_path(base.path()))?,
+        })
+    }
+
+    fn load_other_nodes<M: OpenModeRead>(base: &Base<M>) -> CgnsResult<Vec<OtherNode>> {
+        let cgio = base.file().cgio()?;
+        let node = find_base_node(&cgio, &base.name_bytes()?)?;
+
+        let mut other_nodes = Vec::new();
+        collect_other_nodes(
+            &cgio,
+            node,
+            &CgnsNodeLabel::Base.to_string(),
+            &mut Vec::new(),
+            &mut other_nodes,
+        )?;
+        Ok(other_nodes)
+    }
+
+    fn write<'f, M: OpenModeWrite + 'f>(&self, file: &mut File<'f, M>) -> CgnsResult<()> {
+        let base_index = Base::write(file, &self.data)?;
+        let mut base = Base::new_unchecked(file, base_index);
+
+        if let Some(simulation_type) = &self.simulation_type {
+            SimulationType::write(&mut base, simulation_type)?;
+        }
+        if let Some(ordinal) = &self.ordinal {
+            base.set_ordinal(ordinal)?;
+        }
+        for descriptor in &self.descriptors {
+            base.set_descriptor(descriptor)?;
+        }
+        for zone in &self.zones {
+            zone.write(&mut base, self.data.cell_dim)?;
+        }
+        self.write_other_nodes(file)
+            .map_err(|err| err.with_path(Base::new_unchecked(file, base_index).path()))
+    }
+
+    fn write_other_nodes<'f, M: OpenModeWrite + 'f>(&self, file: &File<'f, M>) -> CgnsResult<()> {
+        if self.other_nodes.is_empty() {
+            return Ok(());
+        }
+        let cgio = file.cgio()?;
+        let base_node = find_base_node(&cgio, self.data.name.as_str().as_bytes())?;
+
+        for other in &self.other_nodes {
+            let mut parent = base_node;
+            for name in &other.parent {
+                parent = cgio.child(parent, name)?;
+            }
+            other.node.write(&cgio, parent)?;
+        }
+        Ok(())
+    }
+}
+
+impl ZoneTree {
+    fn load<M: OpenModeRead>(zone: &Zone<M>) -> CgnsResult<Self> {
+        Ok(ZoneTree {
+            data: zone.read()?,
+            ordinal: if zone.has_ordinal()? {
+                Some(zone.get_ordinal()?)
+            } else {
+                None
+            },
+            descriptors: zone
+                .iter_descriptors()?
+                .map(|descriptor| descriptor.read())
+                .collect::<CgnsResult<_>>()?,
+            grids: zone
+                .grids()?
+                .map(|grid| GridTree::load(&grid))
+                .collect::<CgnsResult<_>>()?,
+            flow_solutions: zone
+                .flow_solutions()?
+                .map(|solution| FlowSolutionTree::load(&solution))
+                .collect::<CgnsResult<_>>()?,
+        })
+    }
+
+    fn write<M: OpenModeWrite>(&self, base: &mut Base<M>, cell_dim: i32) -> CgnsResult<()> {
+        // the cell dimension comes from the tree, `cg_cell_dim` fails for files opened with `CgnsOpenMode::Write`
+        let zone_index = Zone::write_with_cell_dim(base, &self.data, Some(cell_dim))?;
+        let mut zone = Zone::new_unchecked(base, zone_index);
+
+        if let Some(ordinal) = &self.ordinal {
+            zone.set_ordinal(ordinal)?;
+        }
+        for descriptor in &self.descriptors {
+            zone.set_descriptor(descriptor)?;
+        }
+        for grid in &self.grids {
+            let grid_index = Grid::write(&mut zone, &grid.data)?;
+            let grid_node = Grid::new_unchecked(&zone, grid_index);
+
+            for coordinate in &grid.coordinates {
+                Coordinate::write_data(
+                    &grid_node,
+                    &coordinate.name,
+                    &coordinate.data,
+                    &self.data.size,
+                    GridLocation::Vertex,
+                )?;
+            }
+        }
+        for solution in &self.flow_solutions {
+            let solution_index = FlowSolution::write(&mut zone, &solution.data)?;
+            let solution_node = FlowSolution::new_unchecked(&zone, solution_index);
+
+            for field in &solution.fields {
+                Field::write_data(
+                    &solution_node,
+                    &field.name,
+                    &field.data,
+                    &self.data.size,
+                    solution.data.location,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl GridTree {
+    fn load<M: OpenModeRead>(grid: &Grid<M>) -> CgnsResult<Self> {
+        Ok(GridTree {
+            data: grid.read()?,
+            coordinates: grid
+                .coordinates()?
+                .map(|coordinate| coordinate.read())
+                .collect::<CgnsResult<_>>()?,
+        })
+    }
+}
+
+impl FlowSolutionTree {
+    fn load<M: OpenModeRead>(solution: &FlowSolution<M>) -> CgnsResult<Self> {
+        Ok(FlowSolutionTree {
+            data: solution.read()?,
+            fields: solution
+                .fields()?
+                .map(|field| field.read())
+                .collect::<CgnsResult<_>>()?,
+        })
+    }
+}
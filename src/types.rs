@@ -7,6 +7,9 @@ pub enum CgnsNodeLabel {
     SimulationType,
     Descriptor,
     Ordinal,
+    GridCoordinates,
+    DataArray,
+    FlowSolution,
     Custom(String),
 }
 impl std::fmt::Display for CgnsNodeLabel {
@@ -18,11 +21,95 @@ impl std::fmt::Display for CgnsNodeLabel {
             Base => "CGNSBase_t",
             SimulationType => "SimulationType_t",
             Descriptor => "Descriptor_t",
+            GridCoordinates => "GridCoordinates_t",
+            DataArray => "DataArray_t",
+            FlowSolution => "FlowSolution_t",
             Custom(inner) => &inner,
         };
         write!(f, "{}", res)
     }
 }
+impl From<&str> for CgnsNodeLabel {
+    fn from(label: &str) -> Self {
+        use CgnsNodeLabel::*;
+        match label {
+            "Ordinal_t" => Ordinal,
+            "Zone_t" => Zone,
+            "CGNSBase_t" => Base,
+            "SimulationType_t" => SimulationType,
+            "Descriptor_t" => Descriptor,
+            "GridCoordinates_t" => GridCoordinates,
+            "DataArray_t" => DataArray,
+            "FlowSolution_t" => FlowSolution,
+            _ => Custom(label.to_string()),
+        }
+    }
+}
+
+/// Whether libcgns was built with 64-bit sizes (`cgsize_t`).
+/// Otherwise sizes and ranges larger than `i32::MAX` are rejected with a `ConversionError`.
+pub const CG_SIZE_64BIT: bool = std::mem::size_of::<cgns_bindings::cgsize_t>() == 8;
+
+/// Convert sizes or ranges to `cgsize_t`, failing instead of truncating values the library can't represent
+pub(crate) fn to_cgsize(values: &[i64]) -> CgnsResult<Vec<cgns_bindings::cgsize_t>> {
+    use std::convert::TryFrom;
+
+    values
+        .iter()
+        .map(|&value| Ok(cgns_bindings::cgsize_t::try_from(value)?))
+        .collect()
+}
+
+/// A block of points or cells given by its first and last index (both inclusive, starting at 1)
+/// in every index dimension, as stored in `PointRange` or `ElementRange` nodes.
+/// Indices are `i64`, so ranges beyond `i32::MAX` work with libraries that have `CG_SIZE_64BIT`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexRange {
+    pub begin: Vec<i64>,
+    pub end: Vec<i64>,
+}
+impl IndexRange {
+    /// Fails with `CgnsErrorKind::DimensionMismatch` if `begin` and `end` have different lengths
+    pub fn new(begin: Vec<i64>, end: Vec<i64>) -> CgnsResult<Self> {
+        if begin.len() != end.len() {
+            return Err(CgnsError::dimension_mismatch());
+        }
+        Ok(IndexRange { begin, end })
+    }
+
+    /// Split indices in the layout of a `PointRange` node: all of `begin`, then all of `end`
+    pub fn from_indices(indices: &[i64]) -> CgnsResult<Self> {
+        // for an odd number of indices `end` is longer, which `new` rejects
+        let (begin, end) = indices.split_at(indices.len() / 2);
+        Self::new(begin.to_vec(), end.to_vec())
+    }
+
+    /// The indices in the layout libcgns expects for ranges, see `from_indices`
+    pub fn to_cgsize(&self) -> CgnsResult<Vec<cgns_bindings::cgsize_t>> {
+        to_cgsize(&[&self.begin[..], &self.end[..]].concat())
+    }
+}
+
+/// Single indices of points or cells (e.g. a `PointList`) or of the vertices of elements
+/// (an element connectivity). Like `IndexRange` they are `i64`, whatever `cgsize_t` is.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexList(pub Vec<i64>);
+impl IndexList {
+    pub fn to_cgsize(&self) -> CgnsResult<Vec<cgns_bindings::cgsize_t>> {
+        to_cgsize(&self.0)
+    }
+}
+
+/// Decode text read from a file: UTF-8 if it is valid, otherwise Latin-1, which maps every byte to a `char`.
+/// Older files often store names and descriptors in Latin-1.
+pub fn decode_lossy(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&byte| char::from(byte)).collect(),
+    }
+}
 
 pub type CgnsPathNodes = Vec<(CgnsNodeLabel, i32)>;
 
@@ -44,6 +131,42 @@ impl CgnsPath {
         }
     }
 }
+/// Parses the `File_t/<file>/Base_t/<base>/<label>/<index>/...` form produced by `Display`
+impl std::str::FromStr for CgnsPath {
+    type Err = CgnsError;
+
+    fn from_str(path: &str) -> CgnsResult<Self> {
+        fn parse_index(index: Option<&str>) -> CgnsResult<i32> {
+            index
+                .and_then(|index| index.parse().ok())
+                .ok_or_else(CgnsError::invalid_path)
+        }
+
+        let mut parts = path.split('/').filter(|part| !part.is_empty());
+
+        if parts.next() != Some("File_t") {
+            return Err(CgnsError::invalid_path());
+        }
+        let file_number = parse_index(parts.next())?;
+
+        if parts.next() != Some("Base_t") {
+            return Err(CgnsError::invalid_path());
+        }
+        let base_index = parse_index(parts.next())?;
+
+        let mut nodes = Vec::new();
+        while let Some(label) = parts.next() {
+            let index = parse_index(parts.next())?;
+            nodes.push((CgnsNodeLabel::from(label), index));
+        }
+
+        Ok(CgnsPath {
+            file_number,
+            base_index,
+            nodes,
+        })
+    }
+}
 impl std::fmt::Display for CgnsPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut node_path = String::new();
@@ -60,6 +183,69 @@ impl std::fmt::Display for CgnsPath {
     }
 }
 
+/// A path made up of node names, as accepted by `cg_gopath`, e.g. `/Base/Zone/GridCoordinates`.
+/// Relative paths are resolved from the current `goto` position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CgnsNamePath {
+    pub absolute: bool,
+    pub nodes: Vec<String>,
+}
+impl CgnsNamePath {
+    pub fn parent(&self) -> Option<CgnsNamePath> {
+        if self.nodes.is_empty() {
+            None
+        } else {
+            let mut parent = self.clone();
+            parent.nodes.pop();
+
+            Some(parent)
+        }
+    }
+
+    pub fn child(&self, name: &str) -> CgnsNamePath {
+        let mut child = self.clone();
+        child.nodes.push(name.to_string());
+        child
+    }
+}
+impl std::str::FromStr for CgnsNamePath {
+    type Err = CgnsError;
+
+    fn from_str(path: &str) -> CgnsResult<Self> {
+        let absolute = path.starts_with('/');
+        let path = path.trim_start_matches('/').trim_end_matches('/');
+
+        if path.is_empty() {
+            return Ok(CgnsNamePath {
+                absolute,
+                nodes: vec![],
+            });
+        }
+
+        let nodes = path
+            .split('/')
+            .map(|name| {
+                if name.is_empty() {
+                    Err(CgnsError::invalid_path())
+                } else {
+                    Ok(name.to_string())
+                }
+            })
+            .collect::<CgnsResult<_>>()?;
+
+        Ok(CgnsNamePath { absolute, nodes })
+    }
+}
+impl std::fmt::Display for CgnsNamePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.absolute {
+            write!(f, "/")?;
+        }
+        write!(f, "{}", self.nodes.join("/"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum CgnsOpenMode {
     // Closed = cgns_bindings::CG_MODE_CLOSED,
@@ -68,10 +254,83 @@ pub enum CgnsOpenMode {
     Write = cgns_bindings::CG_MODE_WRITE,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum CgnsFileType {
     ADF = cgns_bindings::CG_FILE_ADF,
+    /// ADF files that can be read by CGNS 2.5
     ADF2 = cgns_bindings::CG_FILE_ADF2,
     HDF5 = cgns_bindings::CG_FILE_HDF5,
+    PHDF5 = cgns_bindings::CG_FILE_PHDF5,
     NONE = cgns_bindings::CG_FILE_NONE,
 }
+impl std::fmt::Display for CgnsFileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl std::convert::TryFrom<i32> for CgnsFileType {
+    type Error = CgnsError;
+
+    fn try_from(file_type: i32) -> CgnsResult<Self> {
+        use CgnsFileType::*;
+        Ok(match file_type as u32 {
+            cgns_bindings::CG_FILE_ADF => ADF,
+            cgns_bindings::CG_FILE_ADF2 => ADF2,
+            cgns_bindings::CG_FILE_HDF5 => HDF5,
+            cgns_bindings::CG_FILE_PHDF5 => PHDF5,
+            cgns_bindings::CG_FILE_NONE => NONE,
+            _ => Err(CgnsError::invalid_lib_result())?,
+        })
+    }
+}
+
+/// Where data is located in relation to the grid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GridLocation {
+    Null,
+    UserDefined,
+    Vertex,
+    CellCenter,
+    FaceCenter,
+    IFaceCenter,
+    JFaceCenter,
+    KFaceCenter,
+    EdgeCenter,
+}
+impl From<GridLocation> for cgns_bindings::GridLocation_t {
+    fn from(location: GridLocation) -> Self {
+        use GridLocation::*;
+        match location {
+            Null => cgns_bindings::GridLocation_t_GridLocationNull,
+            UserDefined => cgns_bindings::GridLocation_t_GridLocationUserDefined,
+            Vertex => cgns_bindings::GridLocation_t_Vertex,
+            CellCenter => cgns_bindings::GridLocation_t_CellCenter,
+            FaceCenter => cgns_bindings::GridLocation_t_FaceCenter,
+            IFaceCenter => cgns_bindings::GridLocation_t_IFaceCenter,
+            JFaceCenter => cgns_bindings::GridLocation_t_JFaceCenter,
+            KFaceCenter => cgns_bindings::GridLocation_t_KFaceCenter,
+            EdgeCenter => cgns_bindings::GridLocation_t_EdgeCenter,
+        }
+    }
+}
+impl std::convert::TryFrom<cgns_bindings::GridLocation_t> for GridLocation {
+    type Error = CgnsError;
+
+    fn try_from(location: cgns_bindings::GridLocation_t) -> CgnsResult<Self> {
+        use GridLocation::*;
+        Ok(match location {
+            cgns_bindings::GridLocation_t_GridLocationNull => Null,
+            cgns_bindings::GridLocation_t_GridLocationUserDefined => UserDefined,
+            cgns_bindings::GridLocation_t_Vertex => Vertex,
+            cgns_bindings::GridLocation_t_CellCenter => CellCenter,
+            cgns_bindings::GridLocation_t_FaceCenter => FaceCenter,
+            cgns_bindings::GridLocation_t_IFaceCenter => IFaceCenter,
+            cgns_bindings::GridLocation_t_JFaceCenter => JFaceCenter,
+            cgns_bindings::GridLocation_t_KFaceCenter => KFaceCenter,
+            cgns_bindings::GridLocation_t_EdgeCenter => EdgeCenter,
+            _ => Err(CgnsError::invalid_lib_result())?,
+        })
+    }
+}
@@ -6,6 +6,10 @@ fn create_file(lib: &Library, path: &str) -> CgnsResult<()> {
     Ok(())
 }
 
+fn cgns_name(name: &str) -> CgnsName {
+    CgnsName::new(name).expect("invalid name")
+}
+
 #[test]
 fn lib_single_instance() {
     let lib = Library::take();
@@ -22,13 +26,30 @@ fn lib_two_instances_sequential() {
 }
 
 #[test]
-#[should_panic]
 fn lib_two_instances_parralel() {
     let lib = Library::take();
-    let lib2 = Library::take();
+    assert!(Library::try_take().is_none());
 
     drop(lib);
-    drop(lib2);
+}
+
+#[test]
+fn lib_multiple_threads() {
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            std::thread::spawn(move || {
+                let lib = Library::take();
+                create_file(&lib, &format!("thread_test_{}.cgns", i))
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        thread
+            .join()
+            .expect("thread panicked")
+            .expect("Failed to create file");
+    }
 }
 
 #[test]
@@ -65,7 +86,7 @@ fn test_goto() {
     let base_index = Base::write(
         &mut file,
         &base::BaseData {
-            name: "New Base".into(),
+            name: cgns_name("New Base"),
             cell_dim: 3,
             phys_dim: 3,
         },
@@ -102,7 +123,7 @@ fn iter_bases() {
     Base::write(
         &mut file,
         &base::BaseData {
-            name: "New Base 1".into(),
+            name: cgns_name("New Base 1"),
             cell_dim: 3,
             phys_dim: 3,
         },
@@ -112,7 +133,7 @@ fn iter_bases() {
     Base::write(
         &mut file,
         &base::BaseData {
-            name: "New Base 2".into(),
+            name: cgns_name("New Base 2"),
             cell_dim: 3,
             phys_dim: 3,
         },
@@ -122,7 +143,7 @@ fn iter_bases() {
     Base::write(
         &mut file,
         &base::BaseData {
-            name: "New Base 3".into(),
+            name: cgns_name("New Base 3"),
             cell_dim: 3,
             phys_dim: 3,
         },
@@ -149,7 +170,7 @@ fn read_write_base_and_descriptor() {
         .expect("Failed to open file");
 
     let base_data = base::BaseData {
-        name: "New Base".into(),
+        name: cgns_name("New Base"),
         cell_dim: 3,
         phys_dim: 3,
     };
@@ -163,7 +184,7 @@ fn read_write_base_and_descriptor() {
     assert_eq!(data, base_data);
 
     let descriptor_data = DescriptorData {
-        name: "TestDescriptor".into(),
+        name: cgns_name("TestDescriptor"),
         value: "Test Value".into(),
     };
 
@@ -188,7 +209,7 @@ fn read_write_zone_and_descriptor() {
         .expect("Failed to open file");
 
     let base_data = base::BaseData {
-        name: "New Base".into(),
+        name: cgns_name("New Base"),
         cell_dim: 3,
         phys_dim: 3,
     };
@@ -198,10 +219,10 @@ fn read_write_zone_and_descriptor() {
     let mut base = file.get_base(base_index).expect("failed to get base");
 
     let zone_data = ZoneData {
-        name: "Zone_0001".into(),
+        name: cgns_name("Zone_0001"),
         size: ZoneSize::Structured(StructuredZoneSize {
-            n_cell: (9, 9, 9),
-            n_vertex: (10, 10, 10),
+            n_cell: vec![9, 9, 9],
+            n_vertex: vec![10, 10, 10],
         }),
     };
 
@@ -212,7 +233,7 @@ fn read_write_zone_and_descriptor() {
     assert_eq!(zone.read().expect("Failed to read Zone"), zone_data);
 
     let descriptor_data = DescriptorData {
-        name: "TestDescriptor".into(),
+        name: cgns_name("TestDescriptor"),
         value: "Test Value".into(),
     };
 
@@ -225,3 +246,2265 @@ fn read_write_zone_and_descriptor() {
 
     assert_eq!(descriptor, descriptor_data);
 }
+
+#[test]
+fn path_round_trip() {
+    let path = CgnsPath {
+        file_number: 1,
+        base_index: 2,
+        nodes: vec![(CgnsNodeLabel::Zone, 3), (CgnsNodeLabel::Descriptor, 4)],
+    };
+
+    assert_eq!(
+        path,
+        path.to_string().parse().expect("failed to parse path")
+    );
+
+    let name_path: CgnsNamePath = "/New Base/Zone_0001/GridCoordinates"
+        .parse()
+        .expect("failed to parse name path");
+
+    assert!(name_path.absolute);
+    assert_eq!(name_path.nodes.len(), 3);
+    assert_eq!(name_path.to_string(), "/New Base/Zone_0001/GridCoordinates");
+
+    assert!("File_t/1/Zone_t/1".parse::<CgnsPath>().is_err());
+    assert!("/New Base//Zone_0001".parse::<CgnsNamePath>().is_err());
+}
+
+#[test]
+fn goto_by_name() {
+    let lib = Library::new();
+
+    create_file(&lib, "goto_path_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("goto_path_test.cgns")
+        .expect("failed to open file");
+
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("New Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+
+    let zone_index = Zone::write(
+        &mut base,
+        &ZoneData {
+            name: cgns_name("Zone_0001"),
+            size: ZoneSize::Structured(StructuredZoneSize {
+                n_cell: vec![9, 9, 9],
+                n_vertex: vec![10, 10, 10],
+            }),
+        },
+    )
+    .expect("Failed to write Zone");
+
+    let zone = base.get_zone(zone_index).expect("Failed to get zone");
+
+    file.goto_path("/New Base/Zone_0001")
+        .expect("failed to goto path");
+    assert_eq!(
+        zone.path(),
+        lib.current_path().expect("failed to get current path")
+    );
+}
+
+#[test]
+fn find_by_name() {
+    let lib = Library::new();
+
+    create_file(&lib, "by_name_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("by_name_test.cgns")
+        .expect("failed to open file");
+
+    for name in &["Base 1", "Base 2"] {
+        Base::write(
+            &mut file,
+            &base::BaseData {
+                name: cgns_name(name),
+                cell_dim: 3,
+                phys_dim: 3,
+            },
+        )
+        .expect("failed to write base");
+    }
+
+    let mut base = file.base_by_name("Base 2").expect("failed to find base");
+    assert_eq!(base.index(), 2);
+
+    // a missing base records the path of the file itself
+    let error = file.base_by_name("Base 3").map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), CgnsErrorKind::NodeNotFound);
+    assert_eq!(
+        error.path(),
+        Some(&CgnsPath {
+            file_number: file.file_number(),
+            base_index: 0,
+            nodes: vec![],
+        })
+    );
+
+    Zone::write(
+        &mut base,
+        &ZoneData {
+            name: cgns_name("Zone_0001"),
+            size: ZoneSize::Structured(StructuredZoneSize {
+                n_cell: vec![9, 9, 9],
+                n_vertex: vec![10, 10, 10],
+            }),
+        },
+    )
+    .expect("Failed to write Zone");
+
+    let zone = base.zone_by_name("Zone_0001").expect("failed to find zone");
+    assert_eq!(zone.index(), 1);
+
+    // a missing zone records the path of its base
+    let error = base.zone_by_name("Zone_0002").map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), CgnsErrorKind::NodeNotFound);
+    assert_eq!(error.path(), Some(&base.path()));
+    assert!(format!("{:?}", error).contains("Zone_0002"));
+}
+
+#[test]
+fn error_kind_and_path() {
+    let lib = Library::new();
+
+    create_file(&lib, "error_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("error_test.cgns")
+        .expect("failed to open file");
+
+    let path = CgnsPath {
+        file_number: file.file_number(),
+        base_index: 1,
+        nodes: vec![],
+    };
+    let error = lib
+        .goto(&path)
+        .expect_err("found a base that doesn't exist");
+    assert_eq!(error.path(), Some(&path));
+
+    // a missing base has no existing ancestor below the file
+    let error = file
+        .goto_path("/Missing Base")
+        .expect_err("found a base that doesn't exist");
+    assert_eq!(error.kind(), CgnsErrorKind::NodeNotFound);
+    assert_eq!(error.path(), None);
+
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    // missing nodes record the path of their deepest existing ancestor
+    let error = file
+        .goto_path("/Base/Missing Zone/GridCoordinates")
+        .expect_err("found a zone that doesn't exist");
+    assert_eq!(error.kind(), CgnsErrorKind::NodeNotFound);
+    assert_eq!(
+        error.path(),
+        Some(&CgnsPath {
+            file_number: file.file_number(),
+            base_index,
+            nodes: vec![],
+        })
+    );
+}
+
+#[test]
+fn error_source() {
+    use std::error::Error;
+
+    let error = CgnsError::from(std::ffi::CString::new("nul\0byte").unwrap_err());
+
+    assert_eq!(error.kind(), CgnsErrorKind::ConversionError);
+    assert!(error
+        .source()
+        .expect("missing error source")
+        .is::<std::ffi::NulError>());
+}
+
+#[test]
+fn goto_cache_invalidation() {
+    let lib = Library::new();
+
+    create_file(&lib, "goto_cache_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("goto_cache_test.cgns")
+        .expect("failed to open file");
+
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("New Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+
+    Zone::write(
+        &mut base,
+        &ZoneData {
+            name: cgns_name("Zone_0001"),
+            size: ZoneSize::Structured(StructuredZoneSize {
+                n_cell: vec![9, 9, 9],
+                n_vertex: vec![10, 10, 10],
+            }),
+        },
+    )
+    .expect("Failed to write Zone");
+
+    base.goto().expect("failed to goto base");
+    base.goto().expect("failed to goto base");
+
+    // moving with `goto_path` must not leave a stale cached position behind
+    file.goto_path("/New Base/Zone_0001")
+        .expect("failed to goto path");
+    base.goto().expect("failed to goto base");
+
+    assert_eq!(
+        base.path(),
+        lib.current_path().expect("failed to get current path")
+    );
+}
+
+#[test]
+fn create_file_with_options() {
+    let lib = Library::new();
+
+    let mut file = lib
+        .create("builder_test.cgns")
+        .file_type(CgnsFileType::ADF)
+        .open()
+        .expect("Failed to create file");
+
+    Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("New Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    assert_eq!(
+        file.file_type().expect("failed to read file type"),
+        CgnsFileType::ADF
+    );
+
+    file.close().expect("Failed to close file");
+
+    assert_eq!(
+        lib.is_cgns("builder_test.cgns")
+            .expect("failed to check file"),
+        Some(CgnsFileType::ADF)
+    );
+}
+
+#[test]
+fn create_file_with_file_types() {
+    let lib = Library::new();
+
+    for (filename, file_type) in &[
+        ("builder_hdf5_test.cgns", CgnsFileType::HDF5),
+        ("builder_adf2_test.cgns", CgnsFileType::ADF2),
+    ] {
+        let file = lib
+            .create(filename)
+            .file_type(*file_type)
+            .open()
+            .expect("Failed to create file");
+        assert_eq!(
+            file.file_type().expect("failed to read file type"),
+            *file_type
+        );
+        file.close().expect("Failed to close file");
+
+        let file = lib.open_read(filename).expect("failed to open file");
+        assert_eq!(
+            file.file_type().expect("failed to read file type"),
+            *file_type
+        );
+    }
+}
+
+#[test]
+fn create_file_keeps_default_file_type() {
+    let lib = Library::new();
+
+    lib.configure(CgnsConfig::FileType(CgnsFileType::ADF))
+        .expect("failed to set file type");
+
+    let file = lib
+        .create("builder_override_test.cgns")
+        .file_type(CgnsFileType::HDF5)
+        .open()
+        .expect("Failed to create file");
+    assert_eq!(
+        file.file_type().expect("failed to read file type"),
+        CgnsFileType::HDF5
+    );
+    file.close().expect("Failed to close file");
+
+    // the builder must restore the configured default, not the one of libcgns
+    let file = lib
+        .open_write("builder_default_test.cgns")
+        .expect("Failed to create file");
+    assert_eq!(
+        file.file_type().expect("failed to read file type"),
+        CgnsFileType::ADF
+    );
+    file.close().expect("Failed to close file");
+
+    lib.configure(CgnsConfig::FileType(CgnsFileType::NONE))
+        .expect("failed to reset file type");
+}
+
+#[test]
+fn create_modifiable_file() {
+    let lib = Library::new();
+
+    let mut file = lib
+        .create("builder_modifiable_test.cgns")
+        .file_type(CgnsFileType::HDF5)
+        .modifiable()
+        .open()
+        .expect("Failed to create file");
+    assert_eq!(file.mode(), CgnsOpenMode::Modify);
+    assert_eq!(file.n_bases().expect("failed to count bases"), 0);
+
+    // the file can be read while it is written, e.g. to check the size of the zone
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+    assert_eq!(
+        file.load_tree().expect("failed to load tree"),
+        example_tree()
+    );
+    assert_eq!(
+        file.file_type().expect("failed to read file type"),
+        CgnsFileType::HDF5
+    );
+    file.close().expect("Failed to close file");
+}
+
+#[test]
+fn create_compressed_file() {
+    let lib = Library::new();
+
+    let write_file = |filename: &str, level: i32| {
+        lib.configure(CgnsConfig::Hdf5Compress(level))
+            .expect("failed to set compression");
+        let mut file = lib
+            .create(filename)
+            .file_type(CgnsFileType::HDF5)
+            .modifiable()
+            .open()
+            .expect("Failed to create file");
+        let base_index = Base::write(
+            &mut file,
+            &base::BaseData {
+                name: cgns_name("Base"),
+                cell_dim: 3,
+                phys_dim: 3,
+            },
+        )
+        .expect("failed to write base");
+        let mut base = Base::new_unchecked(&file, base_index);
+        let zone_index = Zone::write(
+            &mut base,
+            &ZoneData::structured("Zone", &[40, 40, 40]).expect("invalid zone"),
+        )
+        .expect("failed to write zone");
+        let mut zone = Zone::new_unchecked(&base, zone_index);
+        let grid_index =
+            Grid::write(&mut zone, &GridData::default()).expect("failed to write grid");
+        let mut grid = Grid::new_unchecked(&zone, grid_index);
+        Coordinate::write_slice(&mut grid, "CoordinateX", &vec![0.0f64; 40 * 40 * 40])
+            .expect("failed to write coordinate");
+        file.close().expect("Failed to close file");
+
+        std::fs::metadata(filename)
+            .expect("failed to read file size")
+            .len()
+    };
+
+    let uncompressed = write_file("builder_uncompressed_test.cgns", 0);
+    let compressed = write_file("builder_compressed_test.cgns", 6);
+    lib.configure(CgnsConfig::Hdf5Compress(0))
+        .expect("failed to reset compression");
+
+    assert!(compressed < uncompressed);
+
+    // invalid levels are rejected before libcgns sees them
+    for level in &[-1, 10] {
+        assert_eq!(
+            lib.configure(CgnsConfig::Hdf5Compress(*level))
+                .expect_err("accepted an invalid compression level")
+                .kind(),
+            CgnsErrorKind::OutOfBounds
+        );
+    }
+}
+
+#[test]
+fn file_metadata() {
+    let lib = Library::new();
+
+    create_file(&lib, "metadata_test.cgns").expect("Failed to create file");
+
+    let file = lib
+        .open_read("metadata_test.cgns")
+        .expect("failed to open file");
+
+    assert!(file.version().expect("failed to read version") >= 3.0);
+    assert!([0, 32, 64].contains(&file.precision().expect("failed to read precision")));
+    file.file_type().expect("failed to read file type");
+
+    assert_eq!(
+        lib.is_cgns("Cargo.toml").expect("failed to check file"),
+        None
+    );
+}
+
+#[test]
+fn configure_library() {
+    let lib = Library::new();
+
+    let compress = lib.compress().expect("failed to read compression");
+
+    lib.configure(CgnsConfig::Compress(1))
+        .expect("failed to set compression");
+    assert_eq!(lib.compress().expect("failed to read compression"), 1);
+
+    lib.configure(CgnsConfig::Compress(compress))
+        .expect("failed to reset compression");
+
+    lib.configure(CgnsConfig::AddPath(".".into()))
+        .expect("failed to add link search path");
+    lib.configure(CgnsConfig::SetPath(None))
+        .expect("failed to clear link search path");
+}
+
+#[test]
+fn custom_error_handler() {
+    let lib = Library::new();
+
+    let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let handler_messages = messages.clone();
+    lib.set_error_handler(move |level, message| {
+        handler_messages
+            .lock()
+            .expect("poisoned lock")
+            .push((level, message.to_string()));
+    })
+    .expect("failed to set error handler");
+
+    // failures are reported to the handler and still returned as errors
+    let err = lib
+        .open_read("does_not_exist.cgns")
+        .expect_err("opened a missing file");
+    let message = std::error::Error::source(&err)
+        .expect("no library error")
+        .to_string();
+    assert!(messages
+        .lock()
+        .expect("poisoned lock")
+        .contains(&(CgnsMessageLevel::Error, message)));
+
+    lib.clear_error_handler()
+        .expect("failed to clear error handler");
+
+    // nothing is reported after the handler was removed
+    let n_messages = messages.lock().expect("poisoned lock").len();
+    lib.open_read("does_not_exist.cgns")
+        .expect_err("opened a missing file");
+    assert_eq!(messages.lock().expect("poisoned lock").len(), n_messages);
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn log_errors() {
+    use std::sync::Mutex;
+
+    struct TestLogger(Mutex<Vec<(log::Level, String)>>);
+    impl log::Log for TestLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+        fn log(&self, record: &log::Record) {
+            self.0
+                .lock()
+                .expect("poisoned lock")
+                .push((record.level(), record.args().to_string()));
+        }
+        fn flush(&self) {}
+    }
+    static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+
+    log::set_logger(&LOGGER).expect("failed to set logger");
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let lib = Library::new();
+    lib.log_errors().expect("failed to set error handler");
+
+    let err = lib
+        .open_read("does_not_exist.cgns")
+        .expect_err("opened a missing file");
+    let message = std::error::Error::source(&err)
+        .expect("no library error")
+        .to_string();
+
+    lib.clear_error_handler()
+        .expect("failed to clear error handler");
+
+    assert!(LOGGER
+        .0
+        .lock()
+        .expect("poisoned lock")
+        .contains(&(log::Level::Error, message)));
+}
+
+#[test]
+fn update_in_place() {
+    let lib = Library::new();
+
+    create_file(&lib, "update_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("update_test.cgns")
+        .expect("failed to open file");
+
+    for name in &["Base 1", "Base 2"] {
+        Base::write(
+            &mut file,
+            &base::BaseData {
+                name: cgns_name(name),
+                cell_dim: 3,
+                phys_dim: 3,
+            },
+        )
+        .expect("failed to write base");
+    }
+
+    let updated_base = base::BaseData {
+        name: cgns_name("Base 1"),
+        cell_dim: 2,
+        phys_dim: 3,
+    };
+    assert_eq!(
+        Base::update(&mut file, &updated_base).expect("failed to update base"),
+        1
+    );
+    assert_eq!(
+        file.get_base(1)
+            .expect("failed to get base")
+            .read()
+            .expect("failed to read base"),
+        updated_base
+    );
+    assert_eq!(file.n_bases().expect("failed to count bases"), 2);
+
+    let mut base = file.get_base(2).expect("failed to get base");
+    for name in &["A", "B", "C"] {
+        base.set_descriptor(&DescriptorData {
+            name: cgns_name(name),
+            value: "old".into(),
+        })
+        .expect("Failed to write descriptor");
+    }
+
+    let updated_descriptor = DescriptorData {
+        name: cgns_name("B"),
+        value: "new".into(),
+    };
+    assert_eq!(
+        base.update_descriptor(&updated_descriptor)
+            .expect("failed to update descriptor"),
+        2
+    );
+    assert_eq!(
+        base.get_descriptor(2).expect("failed to read descriptor"),
+        updated_descriptor
+    );
+    assert_eq!(
+        base.get_descriptor(3)
+            .expect("failed to read descriptor")
+            .name,
+        "C"
+    );
+
+    assert!(base
+        .update_descriptor(&DescriptorData {
+            name: cgns_name("D"),
+            value: "new".into(),
+        })
+        .is_err());
+}
+
+#[test]
+fn update_flow_solution() {
+    let lib = Library::new();
+
+    create_file(&lib, "update_solution_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("update_solution_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let mut zone = base.get_zone(1).expect("Failed to get zone");
+
+    let updated_solution = FlowSolutionData {
+        name: cgns_name("VertexSolution"),
+        location: GridLocation::CellCenter,
+    };
+    assert_eq!(
+        FlowSolution::update(&mut zone, &updated_solution).expect("failed to update solution"),
+        1
+    );
+    assert_eq!(
+        zone.n_flow_solutions().expect("failed to count solutions"),
+        2
+    );
+
+    let solution = zone
+        .get_flow_solution(1)
+        .expect("failed to get flow solution");
+    assert_eq!(
+        solution.read().expect("failed to read solution"),
+        updated_solution
+    );
+    // the fields of the old solution are gone
+    assert_eq!(solution.n_fields().expect("failed to count fields"), 0);
+    assert_eq!(
+        zone.get_flow_solution(2)
+            .expect("failed to get flow solution")
+            .read()
+            .expect("failed to read solution")
+            .name,
+        "CellSolution"
+    );
+
+    assert!(FlowSolution::update(
+        &mut zone,
+        &FlowSolutionData {
+            name: cgns_name("Missing"),
+            location: GridLocation::Vertex,
+        },
+    )
+    .is_err());
+}
+
+#[test]
+fn owned_node_handles() {
+    // handles don't borrow the file, so they can be stored anywhere
+    struct Mesh {
+        zone: NodeHandle,
+        solution: NodeHandle,
+        descriptor: NodeHandle,
+    }
+    fn assert_send<T: Send + 'static>(_: &T) {}
+
+    let lib = Library::new();
+
+    create_file(&lib, "handle_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("handle_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let mesh = {
+        let base = file.get_base(1).expect("Failed to get base");
+        let zone = base.get_zone(1).expect("Failed to get zone");
+        let solution = zone
+            .flow_solution_by_name("CellSolution")
+            .expect("failed to find solution");
+        let descriptor = base
+            .descriptor_by_name("Info")
+            .expect("failed to find descriptor");
+
+        Mesh {
+            zone: NodeHandle::new(&zone),
+            solution: NodeHandle::new(&solution),
+            descriptor: NodeHandle::new(&descriptor),
+        }
+    };
+    assert_send(&mesh.zone);
+
+    // the file can still be modified while handles exist
+    Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Other Base"),
+            cell_dim: 2,
+            phys_dim: 2,
+        },
+    )
+    .expect("failed to write base");
+
+    let zone = mesh.zone.clone();
+    let name = zone
+        .with_zone(&file, |zone| zone.name())
+        .expect("failed to resolve zone")
+        .expect("failed to read zone name");
+    assert_eq!(name, "Zone_0001");
+
+    zone.goto(&file).expect("failed to goto zone");
+    assert_eq!(
+        *zone.path(),
+        lib.current_path().expect("failed to get current path")
+    );
+    assert_eq!(zone.base(&file).expect("failed to resolve base").index(), 1);
+    assert_eq!(zone.file_number(), file.file_number());
+
+    let solution = mesh
+        .solution
+        .with_flow_solution(&file, |solution| solution.read())
+        .expect("failed to resolve solution")
+        .expect("failed to read solution");
+    assert_eq!(solution.name, "CellSolution");
+    assert!(zone.with_flow_solution(&file, |_| ()).is_err());
+
+    let descriptor = mesh
+        .descriptor
+        .with_descriptor(&file, |descriptor| descriptor.read())
+        .expect("failed to resolve descriptor")
+        .expect("failed to read descriptor");
+    assert_eq!(descriptor.value, "written from a tree");
+
+    // descriptors can be written below any node a handle resolves to
+    mesh.solution
+        .with_node(&file, |solution| {
+            solution.set_descriptor(&DescriptorData {
+                name: cgns_name("Note"),
+                value: "from a handle".into(),
+            })
+        })
+        .expect("failed to resolve solution")
+        .expect("failed to write descriptor");
+    let notes = mesh
+        .solution
+        .with_flow_solution(&file, |solution| solution.descriptors_map())
+        .expect("failed to resolve solution")
+        .expect("failed to read descriptors");
+    assert_eq!(notes["Note"], "from a handle");
+
+    // reopening the file invalidates all handles, even if libcgns reuses the file number
+    file.reopen().expect("failed to reopen file");
+    assert_eq!(
+        zone.goto(&file)
+            .expect_err("resolved a handle of the closed file")
+            .kind(),
+        CgnsErrorKind::InvalidPath
+    );
+    let base = file.get_base(1).expect("Failed to get base");
+    let zone = NodeHandle::new(&base.get_zone(1).expect("Failed to get zone"));
+    zone.goto(&file).expect("failed to goto zone");
+
+    // handles are checked against the file they are resolved in
+    file.close().expect("Failed to close file");
+    let other = lib
+        .open_write("handle_other_test.cgns")
+        .expect("Failed to create file");
+    assert_eq!(
+        zone.goto(&other)
+            .expect_err("resolved a handle in another file")
+            .kind(),
+        CgnsErrorKind::InvalidPath
+    );
+}
+
+#[test]
+fn count_children() {
+    let lib = Library::new();
+
+    create_file(&lib, "children_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("children_test.cgns")
+        .expect("failed to open file");
+
+    // a new file has no bases
+    assert_eq!(file.n_bases().expect("failed to count bases"), 0);
+    assert_eq!(
+        ParentNode::<_, Base<_>>::n_children(&file).expect("failed to count bases"),
+        0
+    );
+
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+    assert_eq!(base_index, 1);
+    assert_eq!(file.n_bases().expect("failed to count bases"), 1);
+    assert_eq!(
+        ParentNode::<_, Base<_>>::n_children(&file).expect("failed to count bases"),
+        1
+    );
+
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+    assert_eq!(base.n_zones().expect("failed to count zones"), 0);
+    assert_eq!(
+        base.n_descriptors().expect("failed to count descriptors"),
+        0
+    );
+
+    assert!(!base
+        .has_simulation_type()
+        .expect("failed to check simulation type"));
+    SimulationType::write(&mut base, &SimulationTypeData::TimeAccurate)
+        .expect("failed to write simulation type");
+    assert!(base
+        .has_simulation_type()
+        .expect("failed to check simulation type"));
+
+    assert!(!base.has_ordinal().expect("failed to check ordinal"));
+    base.set_ordinal(&OrdinalData(3))
+        .expect("failed to write ordinal");
+    assert!(base.has_ordinal().expect("failed to check ordinal"));
+
+    base.set_descriptor(&DescriptorData {
+        name: cgns_name("Info"),
+        value: "some info".into(),
+    })
+    .expect("failed to write descriptor");
+    assert_eq!(
+        base.n_descriptors().expect("failed to count descriptors"),
+        1
+    );
+
+    let zone_index = Zone::write(
+        &mut base,
+        &ZoneData {
+            name: cgns_name("Zone_0001"),
+            size: ZoneSize::Structured(StructuredZoneSize {
+                n_cell: vec![9, 9, 9],
+                n_vertex: vec![10, 10, 10],
+            }),
+        },
+    )
+    .expect("Failed to write Zone");
+    assert_eq!(base.n_zones().expect("failed to count zones"), 1);
+
+    let zone = base.get_zone(zone_index).expect("Failed to get zone");
+    assert!(!zone.has_ordinal().expect("failed to check ordinal"));
+    assert_eq!(
+        zone.n_descriptors().expect("failed to count descriptors"),
+        0
+    );
+}
+
+#[test]
+fn edit_descriptors() {
+    let lib = Library::new();
+
+    create_file(&lib, "edit_descriptors_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("edit_descriptors_test.cgns")
+        .expect("failed to open file");
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+
+    for (name, value) in &[("First", "1"), ("Second", "2"), ("Third", "3")] {
+        base.set_descriptor(&DescriptorData {
+            name: cgns_name(name),
+            value: value.to_string(),
+        })
+        .expect("Failed to write descriptor");
+    }
+
+    let names = base
+        .iter_descriptors()
+        .expect("failed to iterate descriptors")
+        .map(|descriptor| descriptor.name())
+        .collect::<CgnsResult<Vec<_>>>()
+        .expect("failed to read descriptor names");
+    assert_eq!(names, vec!["First", "Second", "Third"]);
+
+    base.descriptor_by_name("Second")
+        .expect("failed to find descriptor")
+        .update_value("two")
+        .expect("failed to update descriptor");
+    assert_eq!(
+        base.get_descriptor(2).expect("failed to read descriptor"),
+        DescriptorData {
+            name: cgns_name("Second"),
+            value: "two".into(),
+        }
+    );
+
+    base.descriptor_by_name("First")
+        .expect("failed to find descriptor")
+        .remove()
+        .expect("failed to delete descriptor");
+    base.delete_descriptor("Third")
+        .expect("failed to delete descriptor");
+
+    let descriptors = base.descriptors_map().expect("failed to read descriptors");
+    assert_eq!(descriptors.len(), 1);
+    assert_eq!(descriptors["Second"], "two");
+    // only descriptors are deleted, not other children with the same name
+    Zone::write(
+        &mut base,
+        &ZoneData {
+            name: cgns_name("Zone_0001"),
+            size: ZoneSize::Structured(StructuredZoneSize {
+                n_vertex: vec![2, 2, 2],
+                n_cell: vec![1, 1, 1],
+            }),
+        },
+    )
+    .expect("failed to write zone");
+    let error = base
+        .delete_descriptor("Zone_0001")
+        .expect_err("deleted a zone as a descriptor");
+    assert_eq!(error.kind(), CgnsErrorKind::NodeNotFound);
+    assert_eq!(error.path(), Some(&base.path()));
+    assert_eq!(base.n_zones().expect("failed to count zones"), 1);
+    assert_eq!(base.n_descriptors().expect("failed to count"), 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let zone_data = ZoneData {
+        name: cgns_name("Zone_0001"),
+        size: ZoneSize::Structured(StructuredZoneSize {
+            n_cell: vec![9, 9, 9],
+            n_vertex: vec![10, 10, 10],
+        }),
+    };
+    let json = serde_json::to_string(&zone_data).expect("failed to serialize zone data");
+    let parsed: ZoneData = serde_json::from_str(&json).expect("failed to deserialize zone data");
+    assert_eq!(parsed, zone_data);
+
+    let base_data = base::BaseData {
+        name: cgns_name("Base"),
+        cell_dim: 3,
+        phys_dim: 3,
+    };
+    let json = serde_json::to_string(&base_data).expect("failed to serialize base data");
+    let parsed: base::BaseData =
+        serde_json::from_str(&json).expect("failed to deserialize base data");
+    assert_eq!(parsed, base_data);
+
+    let descriptor_data = DescriptorData {
+        name: cgns_name("Info"),
+        value: "some info".into(),
+    };
+    let json = serde_json::to_string(&descriptor_data).expect("failed to serialize descriptor");
+    let parsed: DescriptorData =
+        serde_json::from_str(&json).expect("failed to deserialize descriptor");
+    assert_eq!(parsed, descriptor_data);
+
+    let json = serde_json::to_string(&SimulationTypeData::TimeAccurate)
+        .expect("failed to serialize simulation type");
+    assert_eq!(
+        serde_json::from_str::<SimulationTypeData>(&json)
+            .expect("failed to deserialize simulation type"),
+        SimulationTypeData::TimeAccurate
+    );
+
+    let json = serde_json::to_string(&OrdinalData(3)).expect("failed to serialize ordinal");
+    assert_eq!(
+        serde_json::from_str::<OrdinalData>(&json).expect("failed to deserialize ordinal"),
+        OrdinalData(3)
+    );
+
+    // names are validated when deserializing
+    assert!(serde_json::from_str::<DescriptorData>(r#"{"name":"a/b","value":""}"#).is_err());
+}
+
+fn example_tree() -> CgnsTree {
+    // 2x3x2 vertices, 1x2x1 cells
+    let n_vertex = 2 * 3 * 2;
+    let coordinate = |name: &str, scale: f64| CoordinateData {
+        name: cgns_name(name),
+        data: ArrayData::RealDouble((0..n_vertex).map(|i| i as f64 * scale).collect()),
+    };
+
+    CgnsTree {
+        bases: vec![BaseTree {
+            data: base::BaseData {
+                name: cgns_name("Base"),
+                cell_dim: 3,
+                phys_dim: 3,
+            },
+            simulation_type: Some(SimulationTypeData::NonTimeAccurate),
+            ordinal: None,
+            descriptors: vec![DescriptorData {
+                name: cgns_name("Info"),
+                value: "written from a tree".into(),
+            }],
+            zones: vec![ZoneTree {
+                data: ZoneData {
+                    name: cgns_name("Zone_0001"),
+                    size: ZoneSize::Structured(StructuredZoneSize {
+                        n_vertex: vec![2, 3, 2],
+                        n_cell: vec![1, 2, 1],
+                    }),
+                },
+                ordinal: Some(OrdinalData(1)),
+                descriptors: vec![],
+                grids: vec![GridTree {
+                    data: GridData::default(),
+                    coordinates: vec![
+                        coordinate("CoordinateX", 1.0),
+                        coordinate("CoordinateY", 0.5),
+                        coordinate("CoordinateZ", 0.25),
+                    ],
+                }],
+                flow_solutions: vec![
+                    FlowSolutionTree {
+                        data: FlowSolutionData {
+                            name: cgns_name("VertexSolution"),
+                            location: GridLocation::Vertex,
+                        },
+                        fields: vec![FieldData {
+                            name: cgns_name("Pressure"),
+                            data: ArrayData::RealDouble(vec![101_325.0; n_vertex]),
+                        }],
+                    },
+                    FlowSolutionTree {
+                        data: FlowSolutionData {
+                            name: cgns_name("CellSolution"),
+                            location: GridLocation::CellCenter,
+                        },
+                        fields: vec![FieldData {
+                            name: cgns_name("Density"),
+                            data: ArrayData::RealDouble(vec![1.2, 1.3]),
+                        }],
+                    },
+                ],
+            }],
+            other_nodes: vec![],
+        }],
+    }
+}
+
+#[test]
+fn tree_round_trip() {
+    let lib = Library::new();
+
+    let tree = example_tree();
+
+    let mut file = lib
+        .open_write("tree_test.cgns")
+        .expect("Failed to create file");
+    file.write_tree(&tree).expect("failed to write tree");
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("tree_test.cgns")
+        .expect("failed to open file");
+    let loaded = file.load_tree().expect("failed to load tree");
+    assert_eq!(loaded, tree);
+}
+
+#[test]
+fn tree_keeps_data_types() {
+    let lib = Library::new();
+
+    let mut tree = example_tree();
+    let zone = &mut tree.bases[0].zones[0];
+    zone.grids[0].coordinates[0].data = ArrayData::RealSingle((0..12).map(|i| i as f32).collect());
+    zone.flow_solutions[1].fields.push(FieldData {
+        name: cgns_name("Material"),
+        data: ArrayData::Integer(vec![3, 7]),
+    });
+
+    let mut file = lib
+        .open_write("tree_types_test.cgns")
+        .expect("Failed to create file");
+    file.write_tree(&tree).expect("failed to write tree");
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("tree_types_test.cgns")
+        .expect("failed to open file");
+    let loaded = file.load_tree().expect("failed to load tree");
+    assert_eq!(loaded, tree);
+    assert_eq!(
+        loaded.bases[0].zones[0].grids[0].coordinates[0]
+            .data
+            .data_type(),
+        DataType::RealSingle
+    );
+}
+
+#[test]
+fn tree_keeps_other_nodes() {
+    let lib = Library::new();
+
+    let mut file = lib
+        .open_write("tree_family_test.cgns")
+        .expect("Failed to create file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    // families aren't modeled by the tree
+    let name = std::ffi::CString::new("Wall").unwrap();
+    let mut family_index = 0;
+    let ier = unsafe {
+        cgns_bindings::cg_family_write(file.file_number(), 1, name.as_ptr(), &mut family_index)
+    };
+    assert_eq!(ier, 0, "failed to write family");
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("tree_family_test.cgns")
+        .expect("failed to open file");
+    let tree = file.load_tree().expect("failed to load tree");
+    let other_nodes = &tree.bases[0].other_nodes;
+    assert_eq!(other_nodes.len(), 1);
+    assert!(other_nodes[0].parent.is_empty());
+    assert_eq!(other_nodes[0].node.name, b"Wall");
+    assert_eq!(other_nodes[0].node.label, "Family_t");
+    file.close().expect("Failed to close file");
+
+    let mut file = lib
+        .open_write("tree_family_copy_test.cgns")
+        .expect("Failed to create file");
+    file.write_tree(&tree).expect("failed to write tree");
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("tree_family_copy_test.cgns")
+        .expect("failed to open file");
+    assert_eq!(file.load_tree().expect("failed to load tree"), tree);
+    let mut n_families = 0;
+    let ier = unsafe { cgns_bindings::cg_nfamilies(file.file_number(), 1, &mut n_families) };
+    assert_eq!(ier, 0, "failed to count families");
+    assert_eq!(n_families, 1);
+}
+
+#[test]
+fn read_write_coordinates_and_fields() {
+    let lib = Library::new();
+
+    create_file(&lib, "arrays_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("arrays_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let mut zone = base.get_zone(1).expect("Failed to get zone");
+    let mut grid = zone.grid_coordinates().expect("failed to find grid");
+
+    assert_eq!(
+        grid.n_coordinates().expect("failed to count coordinates"),
+        3
+    );
+    assert_eq!(
+        grid.coordinate_by_name("CoordinateY")
+            .expect("failed to find coordinate")
+            .index(),
+        2
+    );
+
+    let result = Coordinate::write(
+        &mut grid,
+        &CoordinateData {
+            name: cgns_name("CoordinateW"),
+            data: ArrayData::RealDouble(vec![0.0; 3]),
+        },
+    );
+    assert_eq!(
+        result
+            .expect_err("wrote a coordinate with the wrong length")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+
+    let solution_index = FlowSolution::write(
+        &mut zone,
+        &FlowSolutionData {
+            name: cgns_name("Extra"),
+            location: GridLocation::CellCenter,
+        },
+    )
+    .expect("failed to write flow solution");
+
+    let mut solution = zone
+        .get_flow_solution(solution_index)
+        .expect("failed to get flow solution");
+    let field_data = FieldData {
+        name: cgns_name("Temperature"),
+        data: ArrayData::RealDouble(vec![300.0, 301.0]),
+    };
+    Field::write(&mut solution, &field_data).expect("failed to write field");
+
+    let field = solution
+        .field_by_name("Temperature")
+        .expect("failed to find field");
+    assert_eq!(field.read().expect("failed to read field"), field_data);
+    assert_eq!(
+        field.path().nodes.last(),
+        Some(&(CgnsNodeLabel::DataArray, 1))
+    );
+
+    // coordinates of other grids are addressed through the index of their grid
+    let grid_index = Grid::write(
+        &mut zone,
+        &GridData {
+            name: cgns_name("MovedGrid"),
+        },
+    )
+    .expect("failed to write grid");
+    assert_eq!(grid_index, 2);
+    let mut moved_grid = Grid::new_unchecked(&zone, grid_index);
+    Coordinate::write_slice(&mut moved_grid, "CoordinateX", &[1.0f64; 12])
+        .expect("failed to write coordinate");
+
+    let moved_x = moved_grid
+        .coordinate_by_name("CoordinateX")
+        .expect("failed to find coordinate");
+    assert_eq!(
+        moved_x.path().nodes[1],
+        (CgnsNodeLabel::GridCoordinates, grid_index)
+    );
+    assert_eq!(
+        moved_x
+            .read_vec::<f64>()
+            .expect("failed to read coordinate"),
+        vec![1.0; 12]
+    );
+    let grid = zone.grid_coordinates().expect("failed to find grid");
+    assert_eq!(grid.index(), 1);
+    assert_eq!(
+        grid.coordinate_by_name("CoordinateX")
+            .expect("failed to find coordinate")
+            .read_vec::<f64>()
+            .expect("failed to read coordinate")[1],
+        1.0
+    );
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_coordinates_and_fields() {
+    use ndarray::Array3;
+
+    let lib = Library::new();
+
+    create_file(&lib, "ndarray_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("ndarray_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let zone = base.get_zone(1).expect("Failed to get zone");
+    let mut grid = zone.grid_coordinates().expect("failed to find grid");
+
+    // the example tree stores `i + 2 * j + 6 * k` with i varying fastest
+    let x = grid
+        .coordinate_by_name("CoordinateX")
+        .expect("failed to find coordinate")
+        .read_array::<f64>()
+        .expect("failed to read coordinate");
+    assert_eq!(x.dim(), (2, 3, 2));
+    for ((i, j, k), value) in x.indexed_iter() {
+        assert_eq!(*value, (i + 2 * j + 6 * k) as f64);
+    }
+
+    // a standard (row major) array is reordered on write
+    let w = Array3::from_shape_fn((2, 3, 2), |(i, j, k)| (100 * i + 10 * j + k) as f64);
+    let w_index =
+        Coordinate::write_array(&mut grid, "CoordinateW", w.view()).expect("failed to write array");
+    assert_eq!(
+        grid.get_coordinate(w_index)
+            .expect("failed to get coordinate")
+            .read_array::<f64>()
+            .expect("failed to read coordinate"),
+        w
+    );
+
+    let wrong_shape = Array3::<f64>::zeros((3, 2, 2));
+    assert_eq!(
+        Coordinate::write_array(&mut grid, "CoordinateV", wrong_shape.view())
+            .expect_err("wrote an array with the wrong shape")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+
+    let solution = zone
+        .flow_solution_by_name("CellSolution")
+        .expect("failed to find solution");
+    let density = solution
+        .field_by_name("Density")
+        .expect("failed to find field")
+        .read_array::<f64>()
+        .expect("failed to read field");
+    assert_eq!(density.dim(), (1, 2, 1));
+    assert_eq!(density[[0, 1, 0]], 1.3);
+}
+
+#[test]
+fn read_into_buffers() {
+    use std::mem::MaybeUninit;
+
+    let lib = Library::new();
+
+    create_file(&lib, "buffers_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("buffers_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let zone = base.get_zone(1).expect("Failed to get zone");
+    let mut grid = zone.grid_coordinates().expect("failed to find grid");
+
+    let coordinate = grid
+        .coordinate_by_name("CoordinateY")
+        .expect("failed to find coordinate");
+    let len = coordinate.data_len().expect("failed to get length");
+    assert_eq!(len, 12);
+
+    let mut buffer = vec![0.0; len];
+    coordinate
+        .read_into(&mut buffer)
+        .expect("failed to read coordinate");
+    assert_eq!(
+        ArrayData::RealDouble(buffer),
+        coordinate.read().expect("failed to read coordinate").data
+    );
+
+    let mut short_buffer = vec![0.0; len - 1];
+    assert_eq!(
+        coordinate
+            .read_into(&mut short_buffer)
+            .expect_err("read into a buffer that is too short")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+
+    let values: Vec<f64> = (0..len).map(|i| -(i as f64)).collect();
+    let index =
+        Coordinate::write_slice(&mut grid, "CoordinateW", &values).expect("failed to write slice");
+
+    let solution = zone
+        .flow_solution_by_name("CellSolution")
+        .expect("failed to find solution");
+    let field = solution
+        .field_by_name("Density")
+        .expect("failed to find field");
+    let mut uninit_buffer = [MaybeUninit::<f64>::uninit(); 2];
+    let values = field
+        .read_into_uninit(&mut uninit_buffer)
+        .expect("failed to read field");
+    assert_eq!(values, &[1.2, 1.3]);
+
+    let coordinate = grid
+        .get_coordinate(index)
+        .expect("failed to get coordinate");
+    let mut buffer = vec![0.0; len];
+    coordinate
+        .read_into(&mut buffer)
+        .expect("failed to read coordinate");
+    assert_eq!(buffer[11], -11.0);
+}
+
+#[test]
+fn generic_scalar_types() {
+    let lib = Library::new();
+
+    create_file(&lib, "scalar_types_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("scalar_types_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let zone = base.get_zone(1).expect("Failed to get zone");
+
+    let mut solution = zone
+        .flow_solution_by_name("VertexSolution")
+        .expect("failed to find solution");
+    let cell_types: Vec<i32> = (0..12).collect();
+    let index =
+        Field::write_slice(&mut solution, "CellType", &cell_types).expect("failed to write field");
+
+    let field = solution.get_field(index).expect("failed to get field");
+    assert_eq!(
+        field.data_type().expect("failed to read data type"),
+        DataType::Integer
+    );
+    assert_eq!(
+        field.read_vec::<i32>().expect("failed to read field"),
+        cell_types
+    );
+
+    // values are converted when the requested type differs from the stored one
+    let as_float = field.read_vec::<f32>().expect("failed to read field");
+    assert_eq!(as_float[11], 11.0);
+
+    let grid = zone.grid_coordinates().expect("failed to find grid");
+    let coordinate = grid.get_coordinate(1).expect("failed to get coordinate");
+    assert_eq!(
+        coordinate.data_type().expect("failed to read data type"),
+        f64::DATA_TYPE
+    );
+    let mut buffer = [0i64; 12];
+    coordinate
+        .read_into(&mut buffer)
+        .expect("failed to read coordinate");
+    assert_eq!(buffer[5], 5);
+}
+
+#[test]
+fn large_zone_sizes() {
+    let lib = Library::new();
+
+    create_file(&lib, "large_zone_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("large_zone_test.cgns")
+        .expect("failed to open file");
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+
+    let n_vertex = i64::from(i32::MAX) + 10;
+    let zone_data = ZoneData {
+        name: cgns_name("Large Zone"),
+        size: ZoneSize::Unstructured(UnstructuredZoneSize {
+            n_vertex,
+            n_cell: n_vertex / 4,
+            n_boundary_vertex: 0,
+        }),
+    };
+
+    let result = Zone::write(&mut base, &zone_data);
+
+    if CG_SIZE_64BIT {
+        let zone_index = result.expect("failed to write large zone");
+        let zone = base.get_zone(zone_index).expect("Failed to get zone");
+        assert_eq!(zone.read().expect("failed to read zone"), zone_data);
+        assert_eq!(
+            zone_data
+                .size
+                .len(GridLocation::Vertex)
+                .expect("failed to compute length"),
+            n_vertex as usize
+        );
+    } else {
+        // sizes must not be truncated to 32 bits
+        assert_eq!(
+            result.expect_err("wrote a zone that is too large").kind(),
+            CgnsErrorKind::ConversionError
+        );
+    }
+}
+
+#[test]
+fn large_index_ranges() {
+    let first = i64::from(i32::MAX) + 1;
+    let range = IndexRange::new(vec![1, first], vec![2, first + 9]).expect("invalid range");
+    assert_eq!(
+        IndexRange::from_indices(&[1, first, 2, first + 9]).expect("invalid range"),
+        range
+    );
+    assert_eq!(
+        IndexRange::new(vec![1, 1], vec![2])
+            .expect_err("accepted a range with mixed dimensions")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+    assert_eq!(
+        IndexRange::from_indices(&[1, 2, 3])
+            .expect_err("split an odd number of indices")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+
+    let list = IndexList(vec![1, first]);
+    if CG_SIZE_64BIT {
+        assert_eq!(range.to_cgsize().expect("failed to convert range").len(), 4);
+        assert_eq!(list.to_cgsize().expect("failed to convert list").len(), 2);
+    } else {
+        // indices must not be truncated to 32 bits
+        assert_eq!(
+            range.to_cgsize().expect_err("truncated a range").kind(),
+            CgnsErrorKind::ConversionError
+        );
+        assert_eq!(
+            list.to_cgsize().expect_err("truncated a list").kind(),
+            CgnsErrorKind::ConversionError
+        );
+    }
+}
+
+#[test]
+fn tree_reads_boundary_condition_ranges() {
+    let lib = Library::new();
+
+    let mut file = lib
+        .open_write("tree_boco_test.cgns")
+        .expect("Failed to create file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let range = IndexRange::new(vec![1, 1, 1], vec![2, 3, 1]).expect("invalid range");
+    let points = range.to_cgsize().expect("failed to convert range");
+    let name = std::ffi::CString::new("Wall").unwrap();
+    let mut boco_index = 0;
+    let ier = unsafe {
+        cgns_bindings::cg_boco_write(
+            file.file_number(),
+            1,
+            1,
+            name.as_ptr(),
+            cgns_bindings::BCType_t_BCWall,
+            cgns_bindings::PointSetType_t_PointRange,
+            2,
+            points.as_ptr(),
+            &mut boco_index,
+        )
+    };
+    assert_eq!(ier, 0, "failed to write boundary condition");
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("tree_boco_test.cgns")
+        .expect("failed to open file");
+    let tree = file.load_tree().expect("failed to load tree");
+    let zone_bc = tree.bases[0]
+        .other_nodes
+        .iter()
+        .find(|other| other.node.label == "ZoneBC_t")
+        .expect("lost the boundary conditions");
+    assert_eq!(zone_bc.parent, vec![b"Zone_0001".to_vec()]);
+
+    let boco = &zone_bc.node.children[0];
+    assert_eq!(boco.name, b"Wall");
+    let point_range = boco
+        .children
+        .iter()
+        .find(|node| node.label == "IndexRange_t")
+        .expect("missing point range");
+    let indices = point_range.indices().expect("failed to decode indices");
+    assert_eq!(
+        IndexRange::from_indices(&indices).expect("invalid range"),
+        range
+    );
+}
+
+#[test]
+fn lower_dimensional_zones() {
+    let lib = Library::new();
+
+    create_file(&lib, "lower_dimensional_test.cgns").expect("Failed to create file");
+
+    {
+        let mut file = lib
+            .open_modify("lower_dimensional_test.cgns")
+            .expect("failed to open file");
+
+        for (name, cell_dim) in &[("Surface", 2), ("Line", 1)] {
+            let base_index = Base::write(
+                &mut file,
+                &base::BaseData {
+                    name: cgns_name(name),
+                    cell_dim: *cell_dim,
+                    phys_dim: 3,
+                },
+            )
+            .expect("failed to write base");
+            let mut base = file.get_base(base_index).expect("Failed to get base");
+
+            let n_vertex = vec![4; *cell_dim as usize];
+            Zone::write(
+                &mut base,
+                &ZoneData {
+                    name: cgns_name("Zone"),
+                    size: ZoneSize::Structured(
+                        StructuredZoneSize::new(&n_vertex).expect("invalid zone size"),
+                    ),
+                },
+            )
+            .expect("failed to write zone");
+
+            // the number of index directions has to match the cell dimension
+            let err = Zone::write(
+                &mut base,
+                &ZoneData {
+                    name: cgns_name("Cube"),
+                    size: ZoneSize::Structured(
+                        StructuredZoneSize::new(&[4, 4, 4]).expect("invalid zone size"),
+                    ),
+                },
+            )
+            .expect_err("wrote a zone with the wrong dimension");
+            assert_eq!(err.kind(), CgnsErrorKind::DimensionMismatch);
+        }
+
+        file.close().expect("Failed to close file");
+    }
+
+    let file = lib
+        .open_read("lower_dimensional_test.cgns")
+        .expect("failed to open file");
+
+    let surface = file.get_base(1).expect("Failed to get base");
+    let zone = surface.get_zone(1).expect("Failed to get zone");
+    assert_eq!(zone.index_dim().expect("failed to read index dim"), 2);
+    let size = zone.read().expect("failed to read zone").size;
+    assert_eq!(
+        size,
+        ZoneSize::Structured(StructuredZoneSize {
+            n_vertex: vec![4, 4],
+            n_cell: vec![3, 3],
+        })
+    );
+    assert_eq!(size.len(GridLocation::Vertex).expect("no length"), 16);
+
+    let line = file.get_base(2).expect("Failed to get base");
+    let zone = line.get_zone(1).expect("Failed to get zone");
+    assert_eq!(
+        zone.read().expect("failed to read zone").size,
+        ZoneSize::Structured(StructuredZoneSize {
+            n_vertex: vec![4],
+            n_cell: vec![3],
+        })
+    );
+}
+
+#[test]
+fn zone_size_constructors() {
+    assert_eq!(
+        StructuredZoneSize::new(&[3, 2]).expect("invalid zone size"),
+        StructuredZoneSize {
+            n_vertex: vec![3, 2],
+            n_cell: vec![2, 1],
+        }
+    );
+    assert!(StructuredZoneSize::new(&[]).is_err());
+    assert!(StructuredZoneSize::new(&[2, 2, 2, 2]).is_err());
+    assert!(StructuredZoneSize::new(&[10, 1]).is_err());
+    assert!(StructuredZoneSize {
+        n_vertex: vec![10, 10],
+        n_cell: vec![10, 9],
+    }
+    .validate()
+    .is_err());
+
+    assert!(UnstructuredZoneSize::new(8, 1, 8).is_ok());
+    assert!(UnstructuredZoneSize::new(8, 0, 0).is_err());
+    assert!(UnstructuredZoneSize::new(8, 1, 9).is_err());
+}
+
+#[test]
+fn zone_data_constructors() {
+    assert_eq!(
+        ZoneData::structured("Block", &[10, 10, 10]).expect("invalid zone"),
+        ZoneData {
+            name: cgns_name("Block"),
+            size: ZoneSize::Structured(StructuredZoneSize {
+                n_vertex: vec![10, 10, 10],
+                n_cell: vec![9, 9, 9],
+            }),
+        }
+    );
+    assert_eq!(
+        ZoneData::unstructured("Mesh", 8, 1, 0).expect("invalid zone"),
+        ZoneData {
+            name: cgns_name("Mesh"),
+            size: ZoneSize::Unstructured(UnstructuredZoneSize {
+                n_vertex: 8,
+                n_cell: 1,
+                n_boundary_vertex: 0,
+            }),
+        }
+    );
+
+    let too_long = "Z".repeat(CGNS_NAME_MAX_LEN + 1);
+    assert_eq!(
+        ZoneData::structured(&too_long, &[2, 2])
+            .expect_err("accepted a name that is too long")
+            .kind(),
+        CgnsErrorKind::InvalidName
+    );
+    assert_eq!(
+        ZoneData::unstructured("", 8, 1, 0)
+            .expect_err("accepted an empty name")
+            .kind(),
+        CgnsErrorKind::InvalidName
+    );
+    assert_eq!(
+        ZoneData::structured("Flat", &[2, 1])
+            .expect_err("accepted a zone without cells")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+
+    // invalid data is rejected before it reaches the library
+    let lib = Library::new();
+    create_file(&lib, "zone_data_test.cgns").expect("Failed to create file");
+    let mut file = lib
+        .open_modify("zone_data_test.cgns")
+        .expect("failed to open file");
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+
+    let zone_data = ZoneData {
+        name: cgns_name("Zone"),
+        size: ZoneSize::Structured(StructuredZoneSize {
+            n_vertex: vec![2, 2, 2],
+            n_cell: vec![2, 2, 2],
+        }),
+    };
+    assert_eq!(
+        Zone::write(&mut base, &zone_data)
+            .expect_err("wrote a zone with an invalid size")
+            .kind(),
+        CgnsErrorKind::DimensionMismatch
+    );
+    assert_eq!(base.n_zones().expect("failed to count zones"), 0);
+}
+
+#[test]
+fn cgns_names() {
+    let name = CgnsName::new("Zone_0001").expect("invalid name");
+    assert_eq!(name, "Zone_0001");
+    assert_eq!(name.len(), 9);
+    assert_eq!(String::from(name), "Zone_0001");
+
+    assert!(CgnsName::new(&"N".repeat(CGNS_NAME_MAX_LEN)).is_ok());
+    for invalid in &[
+        String::new(),
+        "N".repeat(CGNS_NAME_MAX_LEN + 1),
+        "Base/Zone".to_string(),
+        ".".to_string(),
+        "..".to_string(),
+    ] {
+        let err = CgnsName::new(invalid).expect_err("accepted an invalid name");
+        assert_eq!(err.kind(), CgnsErrorKind::InvalidName);
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    // names of arrays are checked before they reach the library
+    let lib = Library::new();
+    create_file(&lib, "names_test.cgns").expect("Failed to create file");
+    let mut file = lib
+        .open_modify("names_test.cgns")
+        .expect("failed to open file");
+    file.write_tree(&example_tree())
+        .expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let zone = base.get_zone(1).expect("Failed to get zone");
+    let mut grid = zone.grid_coordinates().expect("failed to find grid");
+    let err = Coordinate::write_slice(&mut grid, "Coordinate/X", &[0.0; 12])
+        .expect_err("wrote a coordinate with an invalid name");
+    assert_eq!(err.kind(), CgnsErrorKind::InvalidName);
+    assert_eq!(
+        grid.n_coordinates().expect("failed to count coordinates"),
+        3
+    );
+}
+
+#[test]
+fn latin1_descriptors() {
+    let lib = Library::new();
+
+    create_file(&lib, "latin1_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("latin1_test.cgns")
+        .expect("failed to open file");
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+    let mut base = file.get_base(base_index).expect("Failed to get base");
+
+    base.set_descriptor(&DescriptorData {
+        name: cgns_name("Plain"),
+        value: "ascii".into(),
+    })
+    .expect("Failed to write descriptor");
+
+    // "Température" and "Messung über Nacht" encoded as Latin-1, as written by older tools
+    let name = std::ffi::CString::new(b"Temp\xe9rature".to_vec()).expect("invalid name");
+    let value = std::ffi::CString::new(b"Messung \xfcber Nacht".to_vec()).expect("invalid value");
+    base.goto().expect("failed to goto base");
+    let ier = unsafe { cgns_bindings::cg_descriptor_write(name.as_ptr(), value.as_ptr()) };
+    to_cgns_result(ier).expect("failed to write descriptor");
+
+    let descriptor = Descriptor::new(&base, 2).expect("Failed to get descriptor");
+    assert_eq!(
+        descriptor
+            .read()
+            .expect_err("decoded Latin-1 as UTF-8")
+            .kind(),
+        CgnsErrorKind::ConversionError
+    );
+    assert_eq!(
+        descriptor.read_bytes().expect("failed to read descriptor"),
+        DescriptorBytes {
+            name: b"Temp\xe9rature".to_vec(),
+            value: b"Messung \xfcber Nacht".to_vec(),
+        }
+    );
+    let data = descriptor.read_lossy().expect("failed to read descriptor");
+    assert_eq!(data.name, "Température");
+    assert_eq!(data.value, "Messung über Nacht");
+
+    let map = base
+        .descriptors_map_lossy()
+        .expect("failed to read descriptors");
+    assert_eq!(map["Plain"], "ascii");
+    assert_eq!(map["Température"], "Messung über Nacht");
+
+    // valid UTF-8 is left as it is
+    assert_eq!(decode_lossy("Température".as_bytes()), "Température");
+
+    // decoded names stay within the length limit
+    assert_eq!(
+        CgnsName::from_bytes_lossy(&[0xe9; 16]),
+        "é".repeat(16).as_str()
+    );
+    let long_name = CgnsName::from_bytes_lossy(&[b"Temp".to_vec(), vec![0xe9; 28]].concat());
+    assert_eq!(long_name.len(), CGNS_NAME_MAX_LEN);
+    assert_eq!(long_name, format!("Temp{}", "?".repeat(28)).as_str());
+
+    // other descriptors can still be found by name
+    assert_eq!(
+        base.get_descriptor(1)
+            .expect("failed to read descriptor")
+            .value,
+        "ascii"
+    );
+    base.delete_descriptor("Plain")
+        .expect("failed to delete descriptor");
+    assert_eq!(base.n_descriptors().expect("failed to count"), 1);
+
+    assert_eq!(base.read_lossy().expect("failed to read base").name, "Base");
+    assert_eq!(base.name_bytes().expect("failed to read name"), b"Base");
+}
+
+#[test]
+fn find_by_name_skips_non_utf8_siblings() {
+    let lib = Library::new();
+
+    create_file(&lib, "non_utf8_siblings_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("non_utf8_siblings_test.cgns")
+        .expect("failed to open file");
+    let mut tree = example_tree();
+    tree.bases[0].zones[0].flow_solutions.clear();
+    file.write_tree(&tree).expect("failed to write tree");
+
+    let base = file.get_base(1).expect("Failed to get base");
+    let mut zone = base.get_zone(1).expect("Failed to get zone");
+
+    // "Lösung" encoded as Latin-1, as written by older tools
+    let name = std::ffi::CString::new(b"L\xf6sung".to_vec()).expect("invalid name");
+    let mut solution_index = 0;
+    let ier = unsafe {
+        cgns_bindings::cg_sol_write(
+            file.file_number(),
+            1,
+            1,
+            name.as_ptr(),
+            GridLocation::CellCenter.into(),
+            &mut solution_index,
+        )
+    };
+    to_cgns_result(ier).expect("failed to write flow solution");
+    FlowSolution::write(
+        &mut zone,
+        &FlowSolutionData {
+            name: cgns_name("CellSolution"),
+            location: GridLocation::CellCenter,
+        },
+    )
+    .expect("failed to write flow solution");
+
+    let latin1 = zone
+        .get_flow_solution(solution_index)
+        .expect("failed to get flow solution");
+    assert_eq!(
+        latin1.read().expect_err("decoded Latin-1 as UTF-8").kind(),
+        CgnsErrorKind::ConversionError
+    );
+    assert_eq!(
+        latin1.read_lossy().expect("failed to read solution").name,
+        "Lösung"
+    );
+    assert_eq!(
+        latin1.name_bytes().expect("failed to read name"),
+        b"L\xf6sung"
+    );
+
+    let mut solution = zone
+        .flow_solution_by_name("CellSolution")
+        .expect("failed to find flow solution");
+    assert_eq!(solution.index(), 2);
+
+    // the same for the fields of a solution
+    let name = std::ffi::CString::new(b"Dichte \xb5".to_vec()).expect("invalid name");
+    let dims: [cgns_bindings::cgsize_t; 3] = [1, 2, 1];
+    let values = [1.2f64, 1.3];
+    solution.goto().expect("failed to goto solution");
+    let ier = unsafe {
+        cgns_bindings::cg_array_write(
+            name.as_ptr(),
+            DataType::RealDouble.into(),
+            3,
+            dims.as_ptr(),
+            values.as_ptr() as *const std::os::raw::c_void,
+        )
+    };
+    to_cgns_result(ier).expect("failed to write field");
+    Field::write_slice(&mut solution, "Pressure", &[1.0f64, 2.0]).expect("failed to write field");
+
+    assert_eq!(
+        solution
+            .field_by_name("Pressure")
+            .expect("failed to find field")
+            .index(),
+        2
+    );
+    let field = solution.get_field(1).expect("failed to get field");
+    assert_eq!(
+        field.read_lossy().expect("failed to read field").name,
+        "Dichte µ"
+    );
+    assert!(matches!(
+        solution.field_by_name("Density"),
+        Err(err) if err.kind() == CgnsErrorKind::NodeNotFound
+    ));
+
+    file.close().expect("Failed to close file");
+}
+
+#[test]
+fn delete_and_rename_bases() {
+    let lib = Library::new();
+
+    create_file(&lib, "delete_base_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("delete_base_test.cgns")
+        .expect("failed to open file");
+
+    for name in &["A", "B", "C"] {
+        Base::write(
+            &mut file,
+            &base::BaseData {
+                name: cgns_name(name),
+                cell_dim: 3,
+                phys_dim: 3,
+            },
+        )
+        .expect("failed to write base");
+    }
+
+    // writing an existing base is an error instead of silently replacing it
+    let err = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("B"),
+            cell_dim: 2,
+            phys_dim: 2,
+        },
+    )
+    .expect_err("overwrote an existing base");
+    assert_eq!(err.kind(), CgnsErrorKind::AlreadyExists);
+    assert_eq!(
+        err.path(),
+        Some(&file.get_base(2).expect("failed to get base").path())
+    );
+    assert_eq!(file.n_bases().expect("failed to count bases"), 3);
+
+    // only bases can be deleted or renamed, not other children of the root
+    assert_eq!(
+        file.delete_base("CGNSLibraryVersion")
+            .expect_err("deleted the library version")
+            .kind(),
+        CgnsErrorKind::NodeNotFound
+    );
+    assert_eq!(
+        file.rename_base("CGNSLibraryVersion", "Version")
+            .expect_err("renamed the library version")
+            .kind(),
+        CgnsErrorKind::NodeNotFound
+    );
+    assert_eq!(
+        file.rename_base("A", "CGNSLibraryVersion")
+            .expect_err("renamed onto the library version")
+            .kind(),
+        CgnsErrorKind::AlreadyExists
+    );
+
+    file.delete_base("B").expect("failed to delete base");
+    assert_eq!(file.n_bases().expect("failed to count bases"), 2);
+    assert_eq!(
+        file.get_base(2)
+            .expect("failed to get base")
+            .name()
+            .expect("failed to read name"),
+        "C"
+    );
+    assert_eq!(
+        file.delete_base("B")
+            .expect_err("deleted a missing base")
+            .kind(),
+        CgnsErrorKind::NodeNotFound
+    );
+
+    file.rename_base("C", "D").expect("failed to rename base");
+    assert_eq!(
+        file.base_by_name("D")
+            .expect("failed to find renamed base")
+            .index(),
+        2
+    );
+    assert_eq!(
+        file.rename_base("A", "D")
+            .expect_err("renamed onto an existing base")
+            .kind(),
+        CgnsErrorKind::AlreadyExists
+    );
+    assert_eq!(
+        file.rename_base("A", "A/B")
+            .expect_err("accepted an invalid name")
+            .kind(),
+        CgnsErrorKind::InvalidName
+    );
+
+    // replacing a base keeps its index, even if its new node is stored after the others
+    let replaced = base::BaseData {
+        name: cgns_name("A"),
+        cell_dim: 2,
+        phys_dim: 2,
+    };
+    assert_eq!(
+        Base::update(&mut file, &replaced).expect("failed to update base"),
+        1
+    );
+    let err = Base::write(&mut file, &replaced).expect_err("overwrote an existing base");
+    assert_eq!(
+        err.path(),
+        Some(&file.get_base(1).expect("failed to get base").path())
+    );
+
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("delete_base_test.cgns")
+        .expect("failed to open file");
+    let names = file
+        .bases()
+        .expect("failed to iterate bases")
+        .map(|base| base.name().expect("failed to read name"))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["A", "D"]);
+}
+
+#[test]
+fn write_zone_to_write_only_file() {
+    let lib = Library::new();
+
+    let mut file = lib
+        .open_write("write_only_zone_test.cgns")
+        .expect("Failed to create file");
+    assert!(!file.is_readable());
+
+    let base_index = Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Surface"),
+            cell_dim: 2,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+    let mut base = Base::new_unchecked(&file, base_index);
+
+    // the cell dimension can't be read, but the zone is still written
+    let zone_data = ZoneData::structured("Zone", &[4, 5]).expect("invalid zone");
+    Zone::write(&mut base, &zone_data).expect("failed to write zone");
+
+    // libcgns still rejects sizes that don't match the cell dimension
+    Zone::write(
+        &mut base,
+        &ZoneData::structured("Cube", &[4, 4, 4]).expect("invalid zone"),
+    )
+    .expect_err("wrote a zone with the wrong dimension");
+
+    file.close().expect("Failed to close file");
+
+    let file = lib
+        .open_read("write_only_zone_test.cgns")
+        .expect("failed to open file");
+    assert!(file.is_readable());
+    let zone = file
+        .get_base(1)
+        .expect("Failed to get base")
+        .get_zone(1)
+        .expect("Failed to get zone")
+        .read()
+        .expect("failed to read zone");
+    assert_eq!(zone, zone_data);
+}
+
+#[test]
+fn reopen_keeps_mode() {
+    let lib = Library::new();
+
+    let mut file = lib
+        .open_dynamic("reopen_mode_test.cgns", CgnsOpenMode::Write)
+        .expect("Failed to create file");
+    Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    // reopening a write-only file would truncate it, so it is left alone
+    assert_eq!(
+        file.reopen()
+            .expect_err("reopened a write-only file")
+            .kind(),
+        CgnsErrorKind::FileMode
+    );
+    assert_eq!(
+        file.delete_base("Base")
+            .expect_err("deleted a base of a write-only file")
+            .kind(),
+        CgnsErrorKind::FileMode
+    );
+    assert!(file.is_open());
+    assert_eq!(file.mode(), CgnsOpenMode::Write);
+    file.close().expect("Failed to close file");
+
+    let mut file = lib
+        .open_dynamic("reopen_mode_test.cgns", CgnsOpenMode::Modify)
+        .expect("failed to open file");
+    file.rename_base("Base", "Renamed")
+        .expect("failed to rename base");
+    assert_eq!(file.mode(), CgnsOpenMode::Modify);
+    assert_eq!(
+        file.get_base(1)
+            .expect("failed to get base")
+            .name()
+            .expect("failed to read name"),
+        "Renamed"
+    );
+    file.close().expect("Failed to close file");
+}
+
+#[test]
+fn reopen_failure() {
+    let lib = Library::new();
+
+    create_file(&lib, "reopen_test.cgns").expect("Failed to create file");
+
+    let mut file = lib
+        .open_modify("reopen_test.cgns")
+        .expect("failed to open file");
+    Base::write(
+        &mut file,
+        &base::BaseData {
+            name: cgns_name("Base"),
+            cell_dim: 3,
+            phys_dim: 3,
+        },
+    )
+    .expect("failed to write base");
+
+    // the file can't be reopened while it is missing
+    std::fs::rename("reopen_test.cgns", "reopen_test_moved.cgns").expect("failed to move file");
+    file.reopen().expect_err("reopened a missing file");
+    assert!(!file.is_open());
+    file.n_bases().expect_err("used a closed file");
+
+    // but it can be reopened again once it is back
+    std::fs::rename("reopen_test_moved.cgns", "reopen_test.cgns").expect("failed to move file");
+    file.reopen().expect("failed to reopen file");
+    assert!(file.is_open());
+    assert_eq!(file.n_bases().expect("failed to count bases"), 1);
+
+    // a closed file is not closed again when it is dropped
+    std::fs::rename("reopen_test.cgns", "reopen_test_moved.cgns").expect("failed to move file");
+    file.reopen().expect_err("reopened a missing file");
+    drop(file);
+    std::fs::remove_file("reopen_test_moved.cgns").expect("failed to remove file");
+}
@@ -1,3 +1,10 @@
+use cgns::*;
+use static_assertions::*;
+
+assert_impl_all!(CgnsError: Send, Sync, std::error::Error);
+assert_impl_all!(LibraryError: Send, Sync, std::error::Error);
+assert_not_impl_any!(Library: Send, Sync);
+
 // TODO
 /*
 use cgns::*;